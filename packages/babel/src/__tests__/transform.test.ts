@@ -26,6 +26,12 @@ describe('transformFilename', () => {
     expect(transformFilename('DatePicker', 'pascalCase')).toBe('DatePicker');
     expect(transformFilename('MyComponent', 'pascalCase')).toBe('MyComponent');
   });
+
+  it('should separate every capital like babel-plugin-import', () => {
+    expect(transformFilename('DatePicker', 'camel2Dash')).toBe('date-picker');
+    expect(transformFilename('QRCode', 'camel2Dash')).toBe('q-r-code');
+    expect(transformFilename('QRCode', 'camel2Underline')).toBe('q_r_code');
+  });
 });
 
 describe('configMatches', () => {
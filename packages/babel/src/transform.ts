@@ -15,9 +15,28 @@ export function transformFilename(name: string, caseType: FilenameCase): string
       return snakeCase(name);
     case 'pascalCase':
       return pascalCase(name);
+    case 'lowerCase':
+      return name.toLowerCase();
+    case 'upperCase':
+      return name.toUpperCase();
+    case 'preserve':
+      return name;
+    case 'camel2Dash':
+      return transCamel(name, '-');
+    case 'camel2Underline':
+      return transCamel(name, '_');
   }
 }
 
+/**
+ * 与 babel-plugin-import 的 transCamel 相同
+ * 首字母小写，其余每个大写字母替换为分隔符加小写字母
+ */
+function transCamel(name: string, separator: string): string {
+  const str = name.charAt(0).toLowerCase() + name.slice(1);
+  return str.replace(/[A-Z]/g, (c) => `${separator}${c.toLowerCase()}`);
+}
+
 /**
  * 检查配置是否匹配给定的组件名
  */
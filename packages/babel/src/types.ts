@@ -1,11 +1,10 @@
+// 此文件由 packages/core 中的 Rust 配置类型生成，请勿手动修改。
+// 修改配置类型后运行 `UPDATE=1 cargo test` 重新生成。
+
 /**
  * 文件名转换规则
  */
-export type FilenameCase =
-  | "kebabCase"
-  | "camelCase"
-  | "snakeCase"
-  | "pascalCase";
+export type FilenameCase = "kebabCase" | "camelCase" | "snakeCase" | "pascalCase" | "lowerCase" | "upperCase" | "preserve" | "camel2Dash" | "camel2Underline";
 
 /**
  * 导入说明符类型
@@ -13,45 +12,41 @@ export type FilenameCase =
 export type SpecifierType = "default" | "named" | "namespace";
 
 /**
- * 转换配置
+ * 单个转换配置
  */
 export interface TransformConfig {
   /**
-   * 源模块名称
+   * 源模块名称（swc 插件中配置了 preset 时可以省略）
    */
   source: string;
-
   /**
-   * 输出路径模板数组
-   * 第一个为主导入，其余为副作用导入
+   * 输出路径模板数组（swc 插件中配置了 preset 时可以省略）
    */
-  output: string[];
-
+  output: Array<string>;
   /**
    * 文件名转换规则
    */
   filename?: FilenameCase;
-
   /**
-   * 导入说明符类型
-   * @default 'default'
+   * 导入说明符类型，默认为 default
    */
   specifier?: SpecifierType;
-
   /**
-   * 只处理列表中的组件名称（白名单）
+   * 只处理指定的组件名称
    */
-  include?: string[];
-
+  include?: Array<string>;
   /**
-   * 排除列表中的组件名称（黑名单）
+   * 排除指定的组件名称
    */
-  exclude?: string[];
+  exclude?: Array<string>;
 }
 
 /**
  * 插件配置
  */
 export interface PluginConfig {
-  config: TransformConfig[];
+  /**
+   * 转换规则列表，按顺序匹配
+   */
+  config: Array<TransformConfig>;
 }
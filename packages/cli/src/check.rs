@@ -0,0 +1,166 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use similar::TextDiff;
+use swc_plugin_transform_import_declaration::native::{LeftoverReason, Rewrite};
+use swc_plugin_transform_import_declaration::transform::ReportLevel;
+use transform_import_declaration_core::suggest::did_you_mean;
+
+/// 生成 `--check` 模式下一个文件的输出
+///
+/// 每个会被重写的导入名称，以及因 include/exclude 或未知导出而保留整包导入的名称各输出一行
+/// `文件:行:列: 信息`，被 preventFullImport 禁止的整包导入同样输出；以 warn/error 级别上报的行带有级别前缀。
+/// 文件会发生变化时再附上统一 diff 预览。
+pub fn render(path: &Path, original: &str, rewrite: &Rewrite) -> String {
+    let display = path.display();
+    let location = |local: &str| match rewrite.locations.get(local) {
+        Some(location) => format!("{display}:{}:{}", location.line, location.column),
+        None => display.to_string(),
+    };
+
+    let mut output = String::new();
+    for specifier in &rewrite.report.rewritten {
+        let paths = specifier.paths.iter().map(|path| format!("'{path}'")).collect::<Vec<_>>();
+        writeln!(
+            output,
+            "{}: '{}' from '{}' would be imported from {}",
+            location(&specifier.local),
+            specifier.imported,
+            specifier.source,
+            paths.join(", ")
+        )
+        .unwrap();
+    }
+    for leftover in &rewrite.report.leftovers {
+        let (imported, source) = (&leftover.imported, &leftover.source);
+        let message = match &leftover.reason {
+            LeftoverReason::Unmatched => format!(
+                "'{imported}' is not matched by any rule for '{source}' and would keep importing the whole library"
+            ),
+            LeftoverReason::UnknownExport { suggestions, .. } => {
+                let suggestions: Vec<&str> = suggestions.iter().map(String::as_str).collect();
+                match did_you_mean(&suggestions) {
+                    Some(help) => format!(
+                        "'{imported}' is not a known export of '{source}' and would be left unchanged ({help})"
+                    ),
+                    None => format!(
+                        "'{imported}' is not a known export of '{source}' and would be left unchanged"
+                    ),
+                }
+            }
+            // 只有 preventFullImport 禁止（且没有被 allowFullImport 放行）时才报告整包导入
+            LeftoverReason::FullImport if leftover.level != ReportLevel::Keep => {
+                let kind = if imported == "*" { "namespace" } else { "default" };
+                format!(
+                    "{kind} import of '{source}' imports the whole library, which 'preventFullImport' forbids"
+                )
+            }
+            // 类型导入和允许的整包导入不属于命名导入的改写范围
+            LeftoverReason::TypeOnly | LeftoverReason::FullImport => continue,
+        };
+        let level = match leftover.level {
+            ReportLevel::Error => "error: ",
+            ReportLevel::Warn => "warning: ",
+            ReportLevel::Keep => "",
+        };
+        writeln!(output, "{}: {level}{message}", location(&leftover.local)).unwrap();
+    }
+
+    if let Some(code) = &rewrite.code {
+        let name = path.to_string_lossy();
+        let diff = TextDiff::from_lines(original, code.as_str());
+        write!(output, "{}", diff.unified_diff().header(&name, &name)).unwrap();
+    }
+    output
+}
+
+/// 文件中是否有以 error 级别上报的保留说明符（`--check` 时以非零状态码退出）
+pub fn has_errors(rewrite: &Rewrite) -> bool {
+    rewrite.report.leftovers.iter().any(|leftover| leftover.level == ReportLevel::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_plugin_transform_import_declaration::native::rewrite_source;
+    use swc_plugin_transform_import_declaration::transform::{
+        FilenameCase, ImportTransformer, PluginConfig, TransformConfig,
+    };
+
+    use super::*;
+    use crate::files::syntax_for;
+
+    #[test]
+    fn test_render() {
+        let config = PluginConfig::builder()
+            .rule(
+                TransformConfig::builder("antd")
+                    .filename(FilenameCase::KebabCase)
+                    .output("antd/es/{{ filename }}")
+                    .output("antd/es/{{ filename }}/style")
+                    .exclude(["message"]),
+            )
+            .build()
+            .unwrap();
+        let path = Path::new("src/app.jsx");
+        let code = "import React from 'react';\nimport { Button as Btn, message } from 'antd';\n\nexport const App = () => <Btn />;\n";
+        let rewrite = rewrite_source(
+            code,
+            syntax_for(path).unwrap(),
+            ImportTransformer::new(config).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            render(path, code, &rewrite),
+            "\
+src/app.jsx:2:10: 'Button' from 'antd' would be imported from 'antd/es/button', 'antd/es/button/style'
+src/app.jsx:2:25: 'message' is not matched by any rule for 'antd' and would keep importing the whole library
+--- src/app.jsx
++++ src/app.jsx
+@@ -1,4 +1,6 @@
+ import React from 'react';
+-import { Button as Btn, message } from 'antd';
++import Btn from 'antd/es/button';
++import 'antd/es/button/style';
++import { message } from 'antd';
+ \n export const App = () => <Btn />;
+"
+        );
+        assert!(!has_errors(&rewrite));
+    }
+
+    #[test]
+    fn test_render_reports_errors() {
+        let config = PluginConfig::builder()
+            .rule(
+                TransformConfig::builder("antd")
+                    .filename(FilenameCase::KebabCase)
+                    .output("antd/es/{{ filename }}")
+                    .exclude(["message"])
+                    .on_unmatched(ReportLevel::Error)
+                    .prevent_full_import(["src/legacy/**"]),
+            )
+            .build()
+            .unwrap();
+        let code = "import antd, { message } from 'antd';\n";
+        let render_for = |path: &str| {
+            let transformer = ImportTransformer::new(config.clone()).unwrap().with_filename(path);
+            let rewrite = rewrite_source(code, Default::default(), transformer).unwrap();
+            (render(Path::new(path), code, &rewrite), has_errors(&rewrite))
+        };
+
+        let (output, errors) = render_for("src/app.js");
+        assert_eq!(
+            output,
+            "\
+src/app.js:1:8: error: default import of 'antd' imports the whole library, which 'preventFullImport' forbids
+src/app.js:1:16: error: 'message' is not matched by any rule for 'antd' and would keep importing the whole library
+"
+        );
+        assert!(errors);
+
+        // allowFullImport 放行的文件不报告整包导入
+        let (output, _) = render_for("src/legacy/app.js");
+        assert!(!output.contains("preventFullImport"));
+    }
+}
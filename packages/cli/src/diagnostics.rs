@@ -0,0 +1,58 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use swc_plugin_transform_import_declaration::native::Diagnostic;
+use swc_plugin_transform_import_declaration::transform::ReportLevel;
+
+/// 将转换时上报的诊断信息格式化为 `文件:行:列: 级别: 信息`，修复建议另起一行
+pub fn render(path: &Path, diagnostics: &[Diagnostic]) -> String {
+    let display = path.display();
+    let mut output = String::new();
+    for diagnostic in diagnostics {
+        let level = if diagnostic.level == ReportLevel::Error { "error" } else { "warning" };
+        match diagnostic.location {
+            Some(location) => write!(output, "{display}:{}:{}", location.line, location.column),
+            None => write!(output, "{display}"),
+        }
+        .unwrap();
+        writeln!(output, ": {level}: {}", diagnostic.message).unwrap();
+        for help in &diagnostic.help {
+            writeln!(output, "  = help: {help}").unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_plugin_transform_import_declaration::native::Location;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let diagnostics = [
+            Diagnostic {
+                level: ReportLevel::Error,
+                location: Some(Location { line: 2, column: 18 }),
+                message: "'Buton' is not a known export of 'antd'".to_string(),
+                help: vec!["did you mean 'Button'?".to_string()],
+            },
+            Diagnostic {
+                level: ReportLevel::Warn,
+                location: None,
+                message: "'message' is not matched by any rule for 'antd'".to_string(),
+                help: vec![],
+            },
+        ];
+
+        assert_eq!(
+            render(Path::new("src/app.jsx"), &diagnostics),
+            "\
+src/app.jsx:2:18: error: 'Buton' is not a known export of 'antd'
+  = help: did you mean 'Button'?
+src/app.jsx: warning: 'message' is not matched by any rule for 'antd'
+"
+        );
+    }
+}
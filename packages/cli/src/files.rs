@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use swc_core::ecma::parser::{EsSyntax, Syntax, TsSyntax};
+
+/// 按扩展名选择解析语法，不支持的文件返回 None
+pub fn syntax_for(path: &Path) -> Option<Syntax> {
+    let syntax = match path.extension()?.to_str()? {
+        "js" | "jsx" | "mjs" | "cjs" => {
+            Syntax::Es(EsSyntax { jsx: true, decorators: true, ..Default::default() })
+        }
+        "ts" | "mts" | "cts" => Syntax::Typescript(TsSyntax {
+            decorators: true,
+            dts: path.to_string_lossy().ends_with(".d.ts"),
+            ..Default::default()
+        }),
+        "tsx" => Syntax::Typescript(TsSyntax { tsx: true, decorators: true, ..Default::default() }),
+        _ => return None,
+    };
+    Some(syntax)
+}
+
+/// 总是排除的目录，与 exclude 参数叠加
+const DEFAULT_EXCLUDES: &[&str] = &["node_modules"];
+
+/// 收集 paths 下所有支持的源文件（按路径排序）
+///
+/// 目录会被递归遍历，遵循 .gitignore 并跳过隐藏文件和 node_modules；include/exclude 是相对于
+/// 所给目录的 gitignore 风格 glob。直接给出的文件不受 glob 限制。
+pub fn collect(
+    paths: &[PathBuf],
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<PathBuf>, ignore::Error> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_file() {
+            files.push(path.clone());
+            continue;
+        }
+
+        let mut overrides = OverrideBuilder::new(path);
+        for glob in include {
+            overrides.add(glob)?;
+        }
+        for glob in DEFAULT_EXCLUDES.iter().copied().chain(exclude.iter().map(String::as_str)) {
+            overrides.add(&format!("!{glob}"))?;
+        }
+        for entry in WalkBuilder::new(path).overrides(overrides.build()?).build() {
+            let entry = entry?;
+            if entry.file_type().is_some_and(|file_type| file_type.is_file())
+                && syntax_for(entry.path()).is_some()
+            {
+                files.push(entry.into_path());
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_syntax_for() {
+        assert!(matches!(
+            syntax_for(Path::new("a.jsx")),
+            Some(Syntax::Es(EsSyntax { jsx: true, .. }))
+        ));
+        assert!(matches!(
+            syntax_for(Path::new("a.tsx")),
+            Some(Syntax::Typescript(TsSyntax { tsx: true, .. }))
+        ));
+        assert!(matches!(
+            syntax_for(Path::new("types/a.d.ts")),
+            Some(Syntax::Typescript(TsSyntax { tsx: false, dts: true, .. }))
+        ));
+        assert!(syntax_for(Path::new("a.css")).is_none());
+        assert!(syntax_for(Path::new("Makefile")).is_none());
+    }
+
+    #[test]
+    fn test_collect() {
+        let root = std::env::temp_dir().join(format!("tid-cli-collect-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in [
+            "src/index.ts",
+            "src/button.tsx",
+            "src/button.test.tsx",
+            "src/style.css",
+            "lib/index.js",
+            "node_modules/antd/index.js",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        let relative = |files: Vec<PathBuf>| -> Vec<String> {
+            files
+                .iter()
+                .map(|file| file.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+                .collect()
+        };
+        let exclude = ["dist".to_string()];
+
+        let files = collect(&[root.clone()], &[], &[]).unwrap();
+        assert_eq!(
+            relative(files),
+            ["lib/index.js", "src/button.test.tsx", "src/button.tsx", "src/index.ts"]
+        );
+
+        // 显式给出的 exclude 不会取消对 node_modules 的排除
+        let files = collect(&[root.clone()], &[], &exclude).unwrap();
+        assert_eq!(
+            relative(files),
+            ["lib/index.js", "src/button.test.tsx", "src/button.tsx", "src/index.ts"]
+        );
+
+        let files =
+            collect(&[root.clone()], &["src/**".to_string()], &["*.test.tsx".to_string()]).unwrap();
+        assert_eq!(relative(files), ["src/button.tsx", "src/index.ts"]);
+
+        // 直接给出的文件不受 glob 限制
+        let files = collect(&[root.join("lib/index.js")], &["src/**".to_string()], &exclude);
+        assert_eq!(relative(files.unwrap()), ["lib/index.js"]);
+
+        assert!(collect(&[root.join("missing")], &[], &exclude).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+//! 将导入转换作为 codemod 应用到源码：把整包（barrel）导入永久改写为按需导入
+//!
+//! ```sh
+//! transform-import-declaration --config import-config.json src
+//! ```
+//!
+//! 配置文件与 swc 插件选项格式相同（`{ "config": [...] }`）。只有命中配置的导入声明会被重写，
+//! 文件其余部分保持原样；文件在多个线程中并行处理。
+//!
+//! 转换时上报的诊断信息（未知的导出名称、被禁止的整包导入等）输出到 stderr，
+//! 有 error 级别诊断信息的文件不会被写入，并以非零状态码退出。
+//!
+//! `--check` 只检查不写入：列出会被重写或保留的导入并输出 diff 预览，有文件会发生变化、
+//! 或有以 error 级别上报的导入（如 `preventFullImport` 禁止的整包导入）时以非零状态码退出，
+//! 可在 CI 中确保发布的源码都使用按需导入。
+
+mod check;
+mod diagnostics;
+mod files;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use clap::Parser;
+use rayon::prelude::*;
+use swc_plugin_transform_import_declaration::native::{TransformError, rewrite_source};
+use swc_plugin_transform_import_declaration::transform::{ImportTransformer, PluginConfig};
+use transform_import_declaration_core::compile::CompiledConfig;
+use transform_import_declaration_core::error::ConfigError;
+
+// 字段的文档注释即命令行帮助信息
+/// Rewrite barrel imports in source files into direct imports
+#[derive(Parser)]
+#[command(name = "transform-import-declaration", version)]
+struct Cli {
+    /// Plugin config file (JSON, same shape as the swc plugin options)
+    #[arg(short, long, value_name = "FILE")]
+    config: PathBuf,
+
+    /// Files or directories to process
+    #[arg(default_value = ".")]
+    paths: Vec<PathBuf>,
+
+    /// Only process files matching this glob, relative to each directory (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    include: Vec<String>,
+
+    /// Skip files matching this glob, relative to each directory (repeatable);
+    /// node_modules is always skipped
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Don't write files: report the imports that would change with a diff preview,
+    /// and exit with status 1 if any file would be rewritten or any import is reported as an error
+    #[arg(long)]
+    check: bool,
+}
+
+/// 单个文件的处理结果
+struct Processed {
+    /// 有导入被重写（`--check` 时为会被重写）
+    rewritten: bool,
+    /// `--check` 时输出的诊断信息和 diff 预览
+    output: String,
+    /// 转换时上报的诊断信息，输出到 stderr
+    diagnostics: String,
+    /// 有 error 级别的诊断信息：文件没有被写入，`--check` 时为有以 error 级别上报的导入
+    failed: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match load_config(&cli.config) {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::from(2);
+        }
+    };
+    let files = match files::collect(&cli.paths, &cli.include, &cli.exclude) {
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    // collect 保持文件顺序，输出与线程调度无关
+    let results: Vec<Result<Processed, String>> =
+        files.par_iter().map(|path| process_file(path, &config, cli.check)).collect();
+
+    let mut rewritten = 0;
+    let mut failed = 0;
+    for (path, result) in files.iter().zip(&results) {
+        match result {
+            Ok(processed) => {
+                print!("{}", processed.output);
+                eprint!("{}", processed.diagnostics);
+                if processed.failed {
+                    failed += 1;
+                    if !cli.check {
+                        eprintln!(
+                            "error: {}: left unchanged because of the errors above",
+                            path.display()
+                        );
+                        continue;
+                    }
+                }
+                if processed.rewritten {
+                    rewritten += 1;
+                    if !cli.check {
+                        println!("{}", path.display());
+                    }
+                }
+            }
+            Err(message) => {
+                failed += 1;
+                eprintln!("error: {message}");
+            }
+        }
+    }
+
+    if cli.check {
+        eprintln!("{rewritten} of {} files would be rewritten", files.len());
+        if rewritten > 0 {
+            return ExitCode::FAILURE;
+        }
+    } else {
+        eprintln!("rewrote {rewritten} of {} files", files.len());
+    }
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// 读取并编译配置，配置警告输出到 stderr
+fn load_config(path: &Path) -> Result<Arc<CompiledConfig>, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    let describe = |error: ConfigError| {
+        let mut message = format!("{}: {error}", path.display());
+        if let Some(hint) = error.hint() {
+            message.push_str(&format!("\n  = help: {hint}"));
+        }
+        message
+    };
+    let (config, warnings) = PluginConfig::load(&json).map_err(describe)?;
+    for warning in warnings {
+        eprintln!("warning: {}: {warning}", path.display());
+        if let Some(hint) = &warning.hint {
+            eprintln!("  = help: {hint}");
+        }
+    }
+    CompiledConfig::new(config).map(Arc::new).map_err(describe)
+}
+
+/// 转换一个文件；check 为 false 时把重写结果写回原文件
+fn process_file(
+    path: &Path,
+    config: &Arc<CompiledConfig>,
+    check: bool,
+) -> Result<Processed, String> {
+    let display = path.display();
+    let syntax =
+        files::syntax_for(path).ok_or_else(|| format!("{display}: unsupported file extension"))?;
+    let code = fs::read_to_string(path).map_err(|error| format!("{display}: {error}"))?;
+
+    let transformer =
+        ImportTransformer::from_compiled(Arc::clone(config)).with_filename(path.to_string_lossy());
+    let rewrite = match rewrite_source(&code, syntax, transformer) {
+        Ok(rewrite) => rewrite,
+        Err(TransformError::Parse { line, column, message }) => {
+            return Err(format!("{display}:{line}:{column}: {message}"));
+        }
+        Err(error) => return Err(format!("{display}: {error}")),
+    };
+
+    if check {
+        let output = check::render(path, &code, &rewrite);
+        return Ok(Processed {
+            rewritten: rewrite.code.is_some(),
+            output,
+            diagnostics: String::new(),
+            failed: check::has_errors(&rewrite),
+        });
+    }
+
+    let diagnostics = diagnostics::render(path, &rewrite.diagnostics);
+    if rewrite.has_errors() {
+        return Ok(Processed {
+            rewritten: false,
+            output: String::new(),
+            diagnostics,
+            failed: true,
+        });
+    }
+    if let Some(output) = &rewrite.code {
+        fs::write(path, output).map_err(|error| format!("{display}: {error}"))?;
+    }
+    Ok(Processed {
+        rewritten: rewrite.code.is_some(),
+        output: String::new(),
+        diagnostics,
+        failed: false,
+    })
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeSet;
+
+use crate::config::{SpecifierType, TransformConfig};
+use crate::error::ConfigWarning;
+use crate::template::Template;
+
+/// 规则能够处理的导入名称集合
+#[derive(Clone, Debug, PartialEq)]
+enum Coverage {
+    /// 只包含列出的名称（include）
+    Only(BTreeSet<String>),
+    /// 包含除列出名称以外的所有名称（exclude 或没有过滤）
+    AllExcept(BTreeSet<String>),
+}
+
+impl Coverage {
+    fn of(rule: &TransformConfig) -> Self {
+        match (&rule.include, &rule.exclude) {
+            (Some(include), _) => Coverage::Only(include.iter().cloned().collect()),
+            (None, Some(exclude)) => Coverage::AllExcept(exclude.iter().cloned().collect()),
+            (None, None) => Coverage::AllExcept(BTreeSet::new()),
+        }
+    }
+
+    fn empty() -> Self {
+        Coverage::Only(BTreeSet::new())
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Coverage::Only(names) if names.is_empty())
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Coverage::Only(a), Coverage::Only(b)) => Coverage::Only(a | b),
+            (Coverage::Only(a), Coverage::AllExcept(e))
+            | (Coverage::AllExcept(e), Coverage::Only(a)) => Coverage::AllExcept(e - a),
+            (Coverage::AllExcept(e), Coverage::AllExcept(f)) => Coverage::AllExcept(e & f),
+        }
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Coverage::Only(a), Coverage::Only(b)) => Coverage::Only(a & b),
+            (Coverage::Only(a), Coverage::AllExcept(e))
+            | (Coverage::AllExcept(e), Coverage::Only(a)) => Coverage::Only(a - e),
+            (Coverage::AllExcept(e), Coverage::AllExcept(f)) => Coverage::AllExcept(e | f),
+        }
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Coverage::Only(a), Coverage::Only(b)) => a.is_subset(b),
+            (Coverage::Only(a), Coverage::AllExcept(e)) => a.is_disjoint(e),
+            (Coverage::AllExcept(_), Coverage::Only(_)) => false,
+            (Coverage::AllExcept(e), Coverage::AllExcept(f)) => f.is_subset(e),
+        }
+    }
+
+    /// 用于警告信息的描述
+    fn describe(&self) -> String {
+        const LIMIT: usize = 5;
+        match self {
+            Coverage::Only(names) => {
+                let mut listed: Vec<String> =
+                    names.iter().take(LIMIT).map(|name| format!("'{name}'")).collect();
+                if names.len() > LIMIT {
+                    listed.push(format!("and {} more", names.len() - LIMIT));
+                }
+                listed.join(", ")
+            }
+            Coverage::AllExcept(names) if names.is_empty() => "every name".to_string(),
+            Coverage::AllExcept(names) => {
+                format!("every name except {}", Coverage::Only(names.clone()).describe())
+            }
+        }
+    }
+}
+
+/// 分析规则顺序，找出无法生效的规则、相互重叠的规则、不包含组件变量的主导入模板和可疑的路径写法
+///
+/// 同一 source 的规则按顺序匹配，第一个匹配的规则生效，
+/// 因此后面的规则中被前面规则覆盖的名称永远不会由后面的规则处理。
+pub fn analyze(rules: &[TransformConfig]) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    for (index, rule) in rules.iter().enumerate() {
+        let coverage = Coverage::of(rule);
+        let earlier: Vec<(usize, &TransformConfig)> =
+            rules[..index].iter().enumerate().filter(|(_, r)| r.source == rule.source).collect();
+        let covered = earlier
+            .iter()
+            .fold(Coverage::empty(), |covered, (_, r)| covered.union(&Coverage::of(r)));
+
+        if !earlier.is_empty() && coverage.is_subset(&covered) {
+            let shadowing: Vec<String> = earlier
+                .iter()
+                .filter(|(_, r)| !Coverage::of(r).intersection(&coverage).is_empty())
+                .map(|(i, _)| format!("#{i}"))
+                .collect();
+            warnings.push(ConfigWarning {
+                path: format!("config[{index}]"),
+                message: format!(
+                    "Config #{index} (source: '{}') is unreachable: every name it matches is \
+                    handled by Config {} first.",
+                    rule.source,
+                    shadowing.join(", ")
+                ),
+                hint: Some(
+                    "Rules for the same source are tried in order and the first match wins. \
+                    Move this rule before the broader one or remove it."
+                        .to_string(),
+                ),
+            });
+        } else {
+            for (earlier_index, earlier_rule) in &earlier {
+                let overlap = Coverage::of(earlier_rule).intersection(&coverage);
+                if overlap.is_empty() {
+                    continue;
+                }
+                warnings.push(ConfigWarning {
+                    path: format!("config[{index}]"),
+                    message: format!(
+                        "Config #{index} (source: '{}') overlaps Config #{earlier_index} on {}; \
+                        Config #{earlier_index} wins for these names.",
+                        rule.source,
+                        overlap.describe()
+                    ),
+                    hint: Some(
+                        "Make the 'include'/'exclude' lists of rules for the same source disjoint."
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+
+        let main_template = rule.output.first().filter(|template| {
+            rule.specifier != Some(SpecifierType::Named) && !has_component_variable(template)
+        });
+        if let Some(template) = main_template {
+            warnings.push(ConfigWarning {
+                path: format!("config[{index}].output[0]"),
+                message: format!(
+                    "Config #{index} (source: '{}'): output template '{template}' does not contain \
+                    '{{{{ filename }}}}', so every component is imported from the same path.",
+                    rule.source
+                ),
+                hint: Some(format!(
+                    "Add '{{{{ filename }}}}' to the path, e.g. \"{}/{{{{ filename }}}}\".",
+                    template.trim_end_matches('/')
+                )),
+            });
+        }
+
+        for (output_index, output) in rule.output.iter().enumerate() {
+            let Ok(template) = Template::parse(output) else {
+                continue;
+            };
+            for problem in template.lint() {
+                warnings.push(ConfigWarning {
+                    path: format!("config[{index}].output[{output_index}]"),
+                    message: format!(
+                        "Config #{index} (source: '{}'): output template '{output}' {problem}.",
+                        rule.source
+                    ),
+                    hint: Some(
+                        "Import paths are used verbatim as module specifiers, check the template."
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+/// 检查模板是否包含组件变量 `{{ filename }}`
+fn has_component_variable(template: &str) -> bool {
+    Template::parse(template).is_ok_and(|template| template.has_filename())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(include: Option<&[&str]>, exclude: Option<&[&str]>) -> TransformConfig {
+        let strings = |values: &[&str]| values.iter().map(|s| s.to_string()).collect();
+        TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}".to_string()],
+            include: include.map(strings),
+            exclude: exclude.map(strings),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_disjoint_rules_are_fine() {
+        let rules = [rule(Some(&["Button"]), None), rule(None, Some(&["Button"]))];
+        assert!(analyze(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_unreachable_after_unfiltered_rule() {
+        let rules = [rule(None, None), rule(Some(&["Button"]), None)];
+
+        let warnings = analyze(&rules);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "config[1]");
+        assert!(warnings[0].message.contains("Config #1 (source: 'antd') is unreachable"));
+        assert!(warnings[0].message.contains("handled by Config #0 first"));
+    }
+
+    #[test]
+    fn test_unreachable_when_covered_by_several_rules() {
+        let rules = [
+            rule(Some(&["Button", "Table"]), None),
+            rule(None, Some(&["Button", "Table", "Form"])),
+            rule(Some(&["Button", "Table", "Modal"]), None),
+        ];
+
+        let warnings = analyze(&rules);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("handled by Config #0, #1 first"));
+    }
+
+    #[test]
+    fn test_overlapping_rules() {
+        let rules = [rule(Some(&["Button", "Table"]), None), rule(Some(&["Table", "Form"]), None)];
+
+        let warnings = analyze(&rules);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("overlaps Config #0 on 'Table'"));
+
+        let rules = [rule(None, Some(&["Button"])), rule(None, Some(&["Table"]))];
+        let warnings = analyze(&rules);
+        assert!(warnings[0].message.contains("overlaps Config #0 on every name except"));
+    }
+
+    #[test]
+    fn test_rules_for_other_sources_are_independent() {
+        let rules = [
+            rule(None, None),
+            TransformConfig { source: "antd-mobile".to_string(), ..rule(None, None) },
+        ];
+        assert!(analyze(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_template_without_component_variable() {
+        let rules = [TransformConfig { output: vec!["antd/es/".to_string()], ..rule(None, None) }];
+
+        let warnings = analyze(&rules);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "config[0].output[0]");
+        assert!(warnings[0].message.contains("every component is imported from the same path"));
+
+        // 命名导入可以有意地从同一个入口导入
+        let rules = [TransformConfig {
+            output: vec!["antd/es/index.js".to_string()],
+            specifier: Some(SpecifierType::Named),
+            ..rule(None, None)
+        }];
+        assert!(analyze(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_suspicious_template_paths() {
+        let rules = [TransformConfig {
+            output: vec![
+                "antd/es/{{ filename }}".to_string(),
+                "antd\\es//{{ filename }}".to_string(),
+            ],
+            ..rule(None, None)
+        }];
+
+        let warnings = analyze(&rules);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].path, "config[0].output[1]");
+        assert!(warnings[0].message.contains("contains '\\'"));
+        assert!(warnings[1].message.contains("contains '//'"));
+    }
+}
@@ -0,0 +1,274 @@
+use serde::Deserialize;
+
+use crate::config::{FilenameCase, SpecifierType, TransformConfig};
+use crate::error::ConfigError;
+
+/// babel-plugin-import 的 style 选项
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(untagged)]
+pub enum BabelPluginImportStyle {
+    /// true 导入 `{path}/style`，false 不导入样式
+    Enabled(bool),
+    /// 只支持 "css"，导入 `{path}/style/css`
+    Name(String),
+}
+
+/// babel-plugin-import 的配置项，用于从 Babel 迁移
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[cfg_attr(test, ts(optional_fields))]
+pub struct BabelPluginImportOptions {
+    /// 源模块名称
+    pub library_name: String,
+    /// 组件所在目录，默认为 lib
+    #[serde(default)]
+    pub library_directory: Option<String>,
+    /// 样式导入方式
+    #[serde(default)]
+    pub style: Option<BabelPluginImportStyle>,
+    /// 样式所在目录，配置后导入 `{libraryName}/{styleLibraryDirectory}/{name}`
+    #[serde(default)]
+    pub style_library_directory: Option<String>,
+    /// 组件目录下的文件名
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// 组件名称转换为连字符形式（camel2Dash），默认为 true
+    #[serde(default)]
+    pub camel2_dash_component_name: Option<bool>,
+    /// 组件名称转换为下划线形式（camel2Underline），优先于 camel2DashComponentName
+    #[serde(default)]
+    pub camel2_underline_component_name: Option<bool>,
+    /// 主导入路径模板，必须包含 `{{ filename }}`
+    #[serde(default)]
+    pub custom_name: Option<String>,
+    /// 自定义样式路径（不支持，仅用于给出明确的错误信息）
+    #[serde(default)]
+    pub custom_style_name: Option<String>,
+    /// 使用默认导入，默认为 true；false 时使用命名导入
+    #[serde(default)]
+    pub transform_to_default_import: Option<bool>,
+}
+
+impl BabelPluginImportOptions {
+    /// 转换为等价的转换规则，无法表示的选项返回错误
+    pub fn to_transform_config(&self, index: usize) -> Result<TransformConfig, ConfigError> {
+        let unsupported = |field: &str, message: String, hint: &str| {
+            ConfigError::unsupported(format!("babelPluginImport[{index}].{field}"), message, hint)
+        };
+
+        let filename = if self.camel2_underline_component_name == Some(true) {
+            FilenameCase::Camel2Underline
+        } else if self.camel2_dash_component_name.unwrap_or(true) {
+            FilenameCase::Camel2Dash
+        } else {
+            FilenameCase::Preserve
+        };
+
+        let main = match &self.custom_name {
+            Some(template) if template.contains("{{") => template.clone(),
+            Some(custom_name) => {
+                return Err(unsupported(
+                    "customName",
+                    format!("customName '{custom_name}' is not a path template."),
+                    "babel-plugin-import loads a string customName as a module exporting a function, \
+                    which cannot run inside the plugin.\n\
+                    Write the path as a template instead, e.g. \"antd/es/{{ filename }}\".",
+                ));
+            }
+            None => join(&[
+                &self.library_name,
+                self.library_directory.as_deref().unwrap_or("lib"),
+                "{{ filename }}",
+                self.file_name.as_deref().unwrap_or_default(),
+            ]),
+        };
+
+        if self.custom_style_name.is_some() {
+            return Err(unsupported(
+                "customStyleName",
+                "customStyleName is not supported.".to_string(),
+                "Use 'styleLibraryDirectory' or 'style', or add the style path to 'output' \
+                of a TransformConfig rule.",
+            ));
+        }
+
+        let style = if let Some(directory) = &self.style_library_directory {
+            Some(join(&[
+                &self.library_name,
+                directory,
+                "{{ filename }}",
+                self.file_name.as_deref().unwrap_or_default(),
+            ]))
+        } else {
+            match &self.style {
+                None | Some(BabelPluginImportStyle::Enabled(false)) => None,
+                Some(BabelPluginImportStyle::Enabled(true)) => Some(format!("{main}/style")),
+                Some(BabelPluginImportStyle::Name(name)) if name == "css" => {
+                    Some(format!("{main}/style/css"))
+                }
+                Some(BabelPluginImportStyle::Name(name)) => {
+                    return Err(unsupported(
+                        "style",
+                        format!("style '{name}' is not supported."),
+                        "Use true to import '{path}/style' or \"css\" to import '{path}/style/css'.\n\
+                        Style functions cannot be serialized; add the style path to 'output' \
+                        of a TransformConfig rule instead.",
+                    ));
+                }
+            }
+        };
+
+        let specifier = if self.transform_to_default_import.unwrap_or(true) {
+            SpecifierType::Default
+        } else {
+            SpecifierType::Named
+        };
+
+        Ok(TransformConfig {
+            source: self.library_name.clone(),
+            output: std::iter::once(main).chain(style).collect(),
+            filename: Some(filename),
+            specifier: Some(specifier),
+            ..Default::default()
+        })
+    }
+}
+
+/// 按 Node.js path.join 的方式拼接路径，忽略空片段
+fn join(segments: &[&str]) -> String {
+    segments
+        .iter()
+        .flat_map(|segment| segment.split('/'))
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(library_name: &str) -> BabelPluginImportOptions {
+        BabelPluginImportOptions { library_name: library_name.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = options("antd").to_transform_config(0).unwrap();
+        assert_eq!(config.source, "antd");
+        assert_eq!(config.output, vec!["antd/lib/{{ filename }}"]);
+        assert_eq!(config.filename, Some(FilenameCase::Camel2Dash));
+        assert_eq!(config.specifier, Some(SpecifierType::Default));
+        // 与 babel-plugin-import 相同，连续的大写字母逐个分隔
+        assert_eq!(config.filename_for("QRCode"), "q-r-code");
+    }
+
+    #[test]
+    fn test_style_options() {
+        let config = BabelPluginImportOptions {
+            library_directory: Some("es".to_string()),
+            style: Some(BabelPluginImportStyle::Enabled(true)),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(config.output, vec!["antd/es/{{ filename }}", "antd/es/{{ filename }}/style"]);
+
+        let config = BabelPluginImportOptions {
+            style: Some(BabelPluginImportStyle::Name("css".to_string())),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(
+            config.output,
+            vec!["antd/lib/{{ filename }}", "antd/lib/{{ filename }}/style/css"]
+        );
+
+        let config = BabelPluginImportOptions {
+            library_directory: Some("es".to_string()),
+            file_name: Some("index.js".to_string()),
+            style_library_directory: Some("theme".to_string()),
+            ..options("@acme/ui")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(
+            config.output,
+            vec!["@acme/ui/es/{{ filename }}/index.js", "@acme/ui/theme/{{ filename }}/index.js"]
+        );
+    }
+
+    #[test]
+    fn test_component_name_options() {
+        let config = BabelPluginImportOptions {
+            camel2_dash_component_name: Some(false),
+            library_directory: Some("".to_string()),
+            transform_to_default_import: Some(false),
+            ..options("lodash")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(config.output, vec!["lodash/{{ filename }}"]);
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+        assert_eq!(config.specifier, Some(SpecifierType::Named));
+
+        let config = BabelPluginImportOptions {
+            camel2_underline_component_name: Some(true),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(config.filename, Some(FilenameCase::Camel2Underline));
+        assert_eq!(config.filename_for("QRCode"), "q_r_code");
+    }
+
+    #[test]
+    fn test_custom_name_template() {
+        let config = BabelPluginImportOptions {
+            custom_name: Some("antd/es/{{ filename }}/index.js".to_string()),
+            style: Some(BabelPluginImportStyle::Enabled(true)),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap();
+        assert_eq!(
+            config.output,
+            vec!["antd/es/{{ filename }}/index.js", "antd/es/{{ filename }}/index.js/style"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_unrepresentable_options() {
+        let error = BabelPluginImportOptions {
+            custom_name: Some("./scripts/custom-name.js".to_string()),
+            ..options("antd")
+        }
+        .to_transform_config(1)
+        .unwrap_err();
+        assert!(error.to_string().contains("`babelPluginImport[1].customName`"));
+        assert!(
+            error
+                .to_string()
+                .contains("customName './scripts/custom-name.js' is not a path template")
+        );
+
+        let error = BabelPluginImportOptions {
+            style: Some(BabelPluginImportStyle::Name("less".to_string())),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap_err();
+        assert!(error.to_string().contains("style 'less' is not supported"));
+
+        let error = BabelPluginImportOptions {
+            custom_style_name: Some("./scripts/style-name.js".to_string()),
+            ..options("antd")
+        }
+        .to_transform_config(0)
+        .unwrap_err();
+        assert!(error.to_string().contains("customStyleName is not supported"));
+    }
+}
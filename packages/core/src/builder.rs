@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use crate::config::{
+    Extends, FilenameCase, PluginConfig, PresetStyle, ReportLevel, SpecifierType, TransformConfig,
+};
+use crate::error::{ConfigError, ConfigWarning};
+
+/// 在代码中构建插件配置
+///
+/// ```
+/// use transform_import_declaration_core::config::{FilenameCase, PluginConfig, TransformConfig};
+///
+/// let config = PluginConfig::builder()
+///     .rule(TransformConfig::builder("antd").preset("antd@5"))
+///     .rule(
+///         TransformConfig::builder("@company/ui")
+///             .filename(FilenameCase::KebabCase)
+///             .output("@company/ui/es/{{ filename }}")
+///             .output("@company/ui/es/{{ filename }}/style"),
+///     )
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.config.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PluginConfigBuilder {
+    config: PluginConfig,
+}
+
+impl PluginConfig {
+    pub fn builder() -> PluginConfigBuilder {
+        PluginConfigBuilder::default()
+    }
+}
+
+impl PluginConfigBuilder {
+    /// 追加一条转换规则（规则按添加顺序匹配）
+    pub fn rule(mut self, rule: impl Into<TransformConfig>) -> Self {
+        self.config.config.push(rule.into());
+        self
+    }
+
+    /// 添加可以通过 extends 引用的规则片段
+    pub fn definition(mut self, name: impl Into<String>, rule: impl Into<TransformConfig>) -> Self {
+        self.config.definitions.get_or_insert_with(HashMap::new).insert(name.into(), rule.into());
+        self
+    }
+
+    /// 展开、验证配置并迁移到当前版本（与加载 JSON 配置的处理相同，忽略配置警告）
+    pub fn build(self) -> Result<PluginConfig, ConfigError> {
+        self.build_with_warnings().map(|(config, _)| config)
+    }
+
+    /// 同 [`build`](Self::build)，同时返回配置警告
+    pub fn build_with_warnings(self) -> Result<(PluginConfig, Vec<ConfigWarning>), ConfigError> {
+        self.config.prepare()
+    }
+}
+
+/// 在代码中构建单条转换规则，未设置的字段与 JSON 配置中省略该字段相同
+#[derive(Clone, Debug, Default)]
+pub struct TransformConfigBuilder {
+    config: TransformConfig,
+}
+
+impl TransformConfig {
+    /// 创建处理 `source` 的规则（使用 preset 时可以传入空字符串）
+    pub fn builder(source: impl Into<String>) -> TransformConfigBuilder {
+        TransformConfigBuilder {
+            config: TransformConfig { source: source.into(), ..Default::default() },
+        }
+    }
+}
+
+impl From<TransformConfigBuilder> for TransformConfig {
+    fn from(builder: TransformConfigBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl TransformConfigBuilder {
+    /// 继承一个规则片段，多次调用时按顺序合并
+    pub fn extends(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.config.extends = Some(match self.config.extends.take() {
+            None => Extends::One(name),
+            Some(extends) => Extends::Many(extends.names().iter().cloned().chain([name]).collect()),
+        });
+        self
+    }
+
+    pub fn preset(mut self, name: impl Into<String>) -> Self {
+        self.config.preset = Some(name.into());
+        self
+    }
+
+    pub fn style(mut self, style: PresetStyle) -> Self {
+        self.config.style = Some(style);
+        self
+    }
+
+    /// 追加一个输出路径模板，第一个为主导入，其余为副作用导入
+    pub fn output(mut self, template: impl Into<String>) -> Self {
+        self.config.output.push(template.into());
+        self
+    }
+
+    pub fn filename(mut self, filename: FilenameCase) -> Self {
+        self.config.filename = Some(filename);
+        self
+    }
+
+    pub fn strip_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.config.strip_prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn specifier(mut self, specifier: SpecifierType) -> Self {
+        self.config.specifier = Some(specifier);
+        self
+    }
+
+    pub fn include<I: IntoIterator<Item = S>, S: Into<String>>(mut self, names: I) -> Self {
+        self.config.include = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn exclude<I: IntoIterator<Item = S>, S: Into<String>>(mut self, names: I) -> Self {
+        self.config.exclude = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 声明组件依赖的其他组件（转换组件时同时生成依赖组件的副作用导入）
+    pub fn dependencies<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        dependencies: I,
+    ) -> Self {
+        self.config
+            .dependencies
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), dependencies.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn exports<I: IntoIterator<Item = S>, S: Into<String>>(mut self, names: I) -> Self {
+        self.config.exports = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn manifest(mut self, manifest: impl Into<String>) -> Self {
+        self.config.manifest = Some(manifest.into());
+        self
+    }
+
+    pub fn on_unknown_export(mut self, level: ReportLevel) -> Self {
+        self.config.on_unknown_export = Some(level);
+        self
+    }
+
+    pub fn on_unmatched(mut self, level: ReportLevel) -> Self {
+        self.config.on_unmatched = Some(level);
+        self
+    }
+
+    /// 禁止整包导入源模块，`allow` 中的文件 glob 除外
+    pub fn prevent_full_import<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        allow: I,
+    ) -> Self {
+        let allow: Vec<String> = allow.into_iter().map(Into::into).collect();
+        self.config.prevent_full_import = Some(true);
+        self.config.allow_full_import = (!allow.is_empty()).then_some(allow);
+        self
+    }
+
+    pub fn build(self) -> TransformConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_matches_json_config() {
+        let built = PluginConfig::builder()
+            .definition("kebab", TransformConfig::builder("").filename(FilenameCase::KebabCase))
+            .rule(
+                TransformConfig::builder("antd")
+                    .extends("kebab")
+                    .output("antd/es/{{ filename }}")
+                    .output("antd/es/{{ filename }}/style")
+                    .exclude(["message"])
+                    .dependencies("Table", ["Spin"]),
+            )
+            .build()
+            .unwrap();
+
+        let loaded = PluginConfig::from_json(
+            r#"{
+                "definitions": { "kebab": { "filename": "kebabCase" } },
+                "config": [{
+                    "source": "antd",
+                    "extends": "kebab",
+                    "output": ["antd/es/{{ filename }}", "antd/es/{{ filename }}/style"],
+                    "exclude": ["message"],
+                    "dependencies": { "Table": ["Spin"] }
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(built.config, loaded.config);
+    }
+
+    #[test]
+    fn test_builder_validates() {
+        let error =
+            PluginConfig::builder().rule(TransformConfig::builder("antd")).build().unwrap_err();
+        assert!(error.to_string().contains("'output' must be a non-empty array."));
+    }
+
+    #[test]
+    fn test_extends_accumulates() {
+        let rule = TransformConfig::builder("antd").extends("a").extends("b").build();
+        assert_eq!(rule.extends, Some(Extends::Many(vec!["a".to_string(), "b".to_string()])));
+    }
+}
@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::compile::CompiledConfig;
+use crate::config::PluginConfig;
+use crate::error::{ConfigError, ConfigWarning};
+
+type Loaded = Result<Arc<CompiledConfig>, ConfigError>;
+
+thread_local! {
+    /// 原始配置字符串 -> 解析、验证并编译后的配置
+    ///
+    /// swc 对每个文件调用一次插件，但同一个 wasm 实例中的配置字符串不会变化，
+    /// 因此每份配置只需要处理一次。
+    static CACHE: RefCell<HashMap<String, Loaded>> = RefCell::new(HashMap::new());
+}
+
+/// 加载配置，相同的配置字符串只解析、验证和编译一次
+///
+/// 配置警告只在第一次加载时返回，之后的调用返回空列表，避免每个文件重复上报；
+/// 配置错误每次都会返回，因为每个文件都会因此保持不变。
+pub fn load(raw: &str) -> (Loaded, Vec<ConfigWarning>) {
+    if let Some(loaded) = CACHE.with(|cache| cache.borrow().get(raw).cloned()) {
+        return (loaded, Vec::new());
+    }
+
+    let (loaded, warnings) = match PluginConfig::load(raw) {
+        Ok((config, warnings)) => (CompiledConfig::new(config).map(Arc::new), warnings),
+        Err(error) => (Err(error), Vec::new()),
+    };
+    CACHE.with(|cache| cache.borrow_mut().insert(raw.to_string(), loaded.clone()));
+    (loaded, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_is_loaded_once() {
+        let raw = r#"{ "config": [
+            { "source": "antd", "output": ["antd/es/{{ filename }}"] },
+            { "source": "antd", "include": ["Button"], "output": ["antd/lib/{{ filename }}"] }
+        ] }"#;
+
+        let (first, warnings) = load(raw);
+        assert_eq!(warnings.len(), 1);
+        let (second, warnings) = load(raw);
+        assert!(warnings.is_empty());
+        assert!(Arc::ptr_eq(&first.unwrap(), &second.unwrap()));
+    }
+
+    #[test]
+    fn test_errors_are_returned_every_time() {
+        let raw = r#"{ "config": [{ "source": "", "output": ["antd/es/{{ filename }}"] }] }"#;
+
+        let (first, _) = load(raw);
+        let (second, _) = load(raw);
+        assert_eq!(first.unwrap_err(), second.unwrap_err());
+    }
+}
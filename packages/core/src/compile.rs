@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::config::{PluginConfig, TransformConfig};
+use crate::error::ConfigError;
+use crate::template::Template;
+
+/// 规则的导入名称过滤条件
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    /// 处理所有名称
+    All,
+    /// 只处理列出的名称（include）
+    Include(HashSet<String>),
+    /// 处理除列出名称以外的所有名称（exclude）
+    Exclude(HashSet<String>),
+}
+
+impl Filter {
+    /// 取出配置中的 include/exclude 列表构建过滤条件（避免复制大量名称）
+    fn take(config: &mut TransformConfig) -> Self {
+        match (config.include.take(), config.exclude.take()) {
+            (Some(include), _) => Filter::Include(include.into_iter().collect()),
+            (None, Some(exclude)) => Filter::Exclude(exclude.into_iter().collect()),
+            (None, None) => Filter::All,
+        }
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Include(names) => names.contains(name),
+            Filter::Exclude(names) => !names.contains(name),
+        }
+    }
+}
+
+/// 预处理后的单条规则
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompiledRule {
+    /// 规则在 config 数组中的下标
+    pub index: usize,
+    /// 规则配置（include/exclude 已移入 filter）
+    pub config: TransformConfig,
+    /// 解析后的 output 模板
+    pub templates: Vec<Template>,
+    pub filter: Filter,
+    /// 有效导出名称集合（exports 和 manifest 合并后）
+    pub known_exports: Option<HashSet<String>>,
+}
+
+impl CompiledRule {
+    fn new(index: usize, mut config: TransformConfig, templates: Vec<Template>) -> Self {
+        let filter = Filter::take(&mut config);
+        let known_exports = config.known_exports().ok().flatten();
+        CompiledRule { index, config, templates, filter, known_exports }
+    }
+
+    /// 检查导入名称是否由这条规则处理
+    pub fn matches(&self, name: &str) -> bool {
+        self.filter.matches(name)
+    }
+}
+
+/// 预处理后的插件配置：按 source 索引规则，模板和过滤条件只解析一次
+#[derive(Clone, Debug, Default)]
+pub struct CompiledConfig {
+    /// source -> 该 source 的规则（保持 config 中的顺序）
+    rules: HashMap<String, Vec<CompiledRule>>,
+}
+
+impl CompiledConfig {
+    /// 编译配置
+    ///
+    /// 经过 [`PluginConfig::prepare`] 的配置直接使用 prepare 时解析好的模板；
+    /// 其他配置（如直接构造的 PluginConfig）在这里验证，配置无效时返回错误。
+    pub fn new(mut config: PluginConfig) -> Result<Self, ConfigError> {
+        let templates = match config.templates.take() {
+            Some(templates) if templates.len() == config.config.len() => templates,
+            _ => config.validated_templates()?,
+        };
+
+        let mut rules: HashMap<String, Vec<CompiledRule>> = HashMap::new();
+        for (index, (config, templates)) in config.config.into_iter().zip(templates).enumerate() {
+            let rule = CompiledRule::new(index, config, templates);
+            rules.entry(rule.config.source.clone()).or_default().push(rule);
+        }
+        Ok(CompiledConfig { rules })
+    }
+
+    /// 获取 source 对应的规则，没有规则时返回空切片
+    pub fn rules_for(&self, source: &str) -> &[CompiledRule] {
+        self.rules.get(source).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(source: &str, include: Option<&[&str]>) -> TransformConfig {
+        TransformConfig {
+            source: source.to_string(),
+            output: vec![format!("{source}/{{{{ filename }}}}")],
+            include: include.map(|names| names.iter().map(|s| s.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rules_are_indexed_by_source_in_order() {
+        let compiled = CompiledConfig::new(PluginConfig {
+            config: vec![rule("antd", Some(&["Button"])), rule("lodash", None), rule("antd", None)],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let antd = compiled.rules_for("antd");
+        assert_eq!(antd.iter().map(|rule| rule.index).collect::<Vec<_>>(), vec![0, 2]);
+        assert!(antd[0].matches("Button"));
+        assert!(!antd[0].matches("Table"));
+        assert_eq!(antd[1].templates[0].render("table"), "antd/table");
+        assert!(compiled.rules_for("react").is_empty());
+    }
+
+    #[test]
+    fn test_uses_templates_parsed_by_prepare() {
+        let (config, _) = PluginConfig { config: vec![rule("antd", None)], ..Default::default() }
+            .prepare()
+            .unwrap();
+        let templates = config.templates.clone().unwrap();
+
+        let compiled = CompiledConfig::new(config).unwrap();
+        assert_eq!(compiled.rules_for("antd")[0].templates, templates[0]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_template_of_unprepared_config() {
+        let invalid =
+            TransformConfig { output: vec!["antd/{{ filename".to_string()], ..rule("antd", None) };
+        let error =
+            CompiledConfig::new(PluginConfig { config: vec![invalid], ..Default::default() })
+                .unwrap_err();
+        assert!(error.to_string().contains("`config[0].output[0]`"), "{error}");
+        assert!(error.to_string().contains("invalid template 'antd/{{ filename'"), "{error}");
+    }
+
+    #[test]
+    fn test_filter() {
+        let exclude = Filter::Exclude(HashSet::from(["message".to_string()]));
+        assert!(exclude.matches("Button"));
+        assert!(!exclude.matches("message"));
+        assert!(Filter::All.matches("anything"));
+    }
+}
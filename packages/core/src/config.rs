@@ -0,0 +1,977 @@
+use std::collections::{HashMap, HashSet};
+
+use heck::{ToKebabCase, ToLowerCamelCase, ToPascalCase, ToSnakeCase};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis;
+use crate::babel_plugin_import::BabelPluginImportOptions;
+use crate::error::{ConfigError, ConfigWarning};
+use crate::extends;
+use crate::glob::glob_matches;
+use crate::modularize_imports::{self, ModularizeImportsOptions};
+use crate::preset;
+use crate::template::Template;
+use crate::version::{self, CURRENT_DEFAULTS};
+
+/// 文件名转换规则
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Copy)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum FilenameCase {
+    /// 小写字母，用连字符分隔：DatePicker → date-picker
+    KebabCase,
+    /// 驼峰命名，首字母小写：DatePicker → datePicker
+    CamelCase,
+    /// 小写字母，用下划线分隔：DatePicker → date_picker
+    SnakeCase,
+    /// 帕斯卡命名，首字母大写：DatePicker → DatePicker
+    PascalCase,
+    /// 全部小写，不加分隔符：DatePicker → datepicker
+    LowerCase,
+    /// 全部大写，不加分隔符：DatePicker → DATEPICKER
+    UpperCase,
+    /// 保持原样：debounce → debounce
+    Preserve,
+    /// 与 babel-plugin-import 的 camel2DashComponentName 相同，每个大写字母前加连字符：QRCode → q-r-code
+    Camel2Dash,
+    /// 与 babel-plugin-import 的 camel2UnderlineComponentName 相同，每个大写字母前加下划线：QRCode → q_r_code
+    Camel2Underline,
+}
+
+/// 导入说明符类型
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Copy)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum SpecifierType {
+    /// import Button from "path"
+    Default,
+    /// import { Button } from "path"
+    Named,
+    /// import * as Button from "path"
+    Namespace,
+}
+
+/// 预设的样式文件类型
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Copy)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum PresetStyle {
+    /// 编译后的 CSS
+    Css,
+    /// Less 源文件
+    Less,
+    /// Sass 源文件
+    Scss,
+}
+
+impl PresetStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PresetStyle::Css => "css",
+            PresetStyle::Less => "less",
+            PresetStyle::Scss => "scss",
+        }
+    }
+}
+
+/// 诊断级别（按严重程度从低到高排列）
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub enum ReportLevel {
+    /// 不报告，保持原样
+    Keep,
+    /// 报告警告
+    Warn,
+    /// 报告错误，构建失败
+    Error,
+}
+
+/// 引用的规则片段名称（单个名称或名称列表）
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(untagged)]
+pub enum Extends {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Extends {
+    /// 按合并顺序返回片段名称
+    pub fn names(&self) -> &[String] {
+        match self {
+            Extends::One(name) => std::slice::from_ref(name),
+            Extends::Many(names) => names,
+        }
+    }
+}
+
+/// 单个转换配置
+// 标记了 ts(skip) 的字段只在 swc 插件中可用，不会出现在 Babel 插件的类型声明中
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[cfg_attr(test, ts(optional_fields))]
+pub struct TransformConfig {
+    /// 继承 definitions 中的规则片段，多个片段按顺序合并，规则自身的字段最后合并
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub extends: Option<Extends>,
+    /// 内置预设名称（如 antd@5、lodash），展开为完整的规则，规则中的其他字段覆盖预设的值
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub preset: Option<String>,
+    /// 预设的样式文件类型（仅在配置了 preset 时可用）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub style: Option<PresetStyle>,
+    /// 源模块名称（swc 插件中配置了 preset 时可以省略）
+    #[serde(default)]
+    pub source: String,
+    /// 输出路径模板数组（swc 插件中配置了 preset 时可以省略）
+    #[serde(default)]
+    pub output: Vec<String>,
+    /// 文件名转换规则
+    #[serde(default)]
+    pub filename: Option<FilenameCase>,
+    /// 生成文件名前从导入名称中去掉的前缀（如 element-plus 的 El）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub strip_prefix: Option<String>,
+    /// 导入说明符类型，默认为 default
+    #[serde(default)]
+    pub specifier: Option<SpecifierType>,
+    /// 只处理指定的组件名称
+    #[serde(default)]
+    pub include: Option<Vec<String>>,
+    /// 排除指定的组件名称
+    #[serde(default)]
+    pub exclude: Option<Vec<String>>,
+    /// 组件依赖清单（组件名 -> 依赖的其他组件名）
+    /// 转换组件时会同时为依赖组件生成副作用导入（如样式文件）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub dependencies: Option<HashMap<String, Vec<String>>>,
+    /// 源模块的有效导出名称列表
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub exports: Option<Vec<String>>,
+    /// 有效导出名称清单（JSON 字符串，可以是名称数组或以名称为键的对象）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub manifest: Option<String>,
+    /// 导入了不在 exports/manifest 中的名称时的处理方式，默认为 error
+    /// 无论哪种级别，未知名称都不会被转换
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub on_unknown_export: Option<ReportLevel>,
+    /// 命名导入没有被任何规则处理（保留了整包导入）时的处理方式，默认为 keep
+    /// 同一 source 有多条规则时，取其中最严格的级别
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub on_unmatched: Option<ReportLevel>,
+    /// 禁止对源模块使用默认导入或命名空间导入（整包导入）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub prevent_full_import: Option<bool>,
+    /// 允许整包导入的文件 glob 列表（仅在 prevent_full_import 为 true 时生效）
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub allow_full_import: Option<Vec<String>>,
+}
+
+/// 插件配置
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[cfg_attr(test, ts(optional_fields))]
+pub struct PluginConfig {
+    /// JSON Schema 地址，仅用于编辑器补全和校验，插件会忽略它
+    #[serde(default, rename = "$schema")]
+    #[cfg_attr(test, ts(skip))]
+    pub schema: Option<String>,
+    /// 配置版本，默认为 1。加载时按声明版本的默认值填入规则中未配置的字段
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub version: Option<u32>,
+    /// 可复用的规则片段（名称 -> 片段），供规则通过 extends 引用
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub definitions: Option<HashMap<String, TransformConfig>>,
+    /// 转换规则列表，按顺序匹配
+    #[serde(default)]
+    pub config: Vec<TransformConfig>,
+    /// babel-plugin-import 的配置项，转换后追加在 config 的规则之后
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub babel_plugin_import: Option<Vec<BabelPluginImportOptions>>,
+    /// swc/Next.js modularizeImports 格式的配置（包名 -> 配置），按书写顺序转换后追加在最后
+    ///
+    /// 插件选项也可以直接写成这种格式（不含其他字段），与 swc_plugin_modularize_imports 的选项相同。
+    #[serde(default)]
+    #[cfg_attr(test, ts(skip))]
+    pub modularize_imports: Option<IndexMap<String, ModularizeImportsOptions>>,
+    /// prepare 时解析好的 output 模板（与 config 中的规则一一对应），编译配置时直接使用
+    #[serde(skip)]
+    pub templates: Option<Vec<Vec<Template>>>,
+}
+
+impl PluginConfig {
+    /// 从 JSON 字符串解析并验证配置（忽略配置警告）
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        Self::load(json).map(|(config, _)| config)
+    }
+
+    /// 从 JSON 字符串解析、验证配置并迁移到当前版本，同时返回配置警告
+    pub fn load(json: &str) -> Result<(Self, Vec<ConfigWarning>), ConfigError> {
+        let deserializer = &mut serde_json::Deserializer::from_str(json);
+        let config = if modularize_imports::is_package_map(json) {
+            let packages =
+                serde_path_to_error::deserialize(deserializer).map_err(ConfigError::from_json)?;
+            PluginConfig { modularize_imports: Some(packages), ..Default::default() }
+        } else {
+            serde_path_to_error::deserialize(deserializer).map_err(ConfigError::from_json)?
+        };
+        config.prepare()
+    }
+
+    /// 展开、验证配置并迁移到当前版本，同时返回配置警告（用于在代码中构建的配置）
+    pub fn prepare(self) -> Result<(Self, Vec<ConfigWarning>), ConfigError> {
+        // 经过 extends 或 preset 展开的规则，验证失败时附带展开后的完整规则
+        let expanded: Vec<bool> = self
+            .config
+            .iter()
+            .map(|rule| rule.extends.is_some() || rule.preset.is_some())
+            .collect();
+        let preset_warnings = preset::warnings(&self);
+        let config = self.resolve()?;
+        let templates = config.validated_templates().map_err(|error| match error.rule_index() {
+            // 由兼容配置转换得到的规则排在最后，同样视为展开后的规则
+            Some(index) if expanded.get(index).copied().unwrap_or(true) => {
+                error.with_resolved_rule(&config.config[index])
+            }
+            _ => error,
+        })?;
+
+        let warnings = analysis::analyze(&config.config);
+        let (mut config, migration_warnings) = version::migrate(config)?;
+        config.templates = Some(templates);
+        Ok((config, [preset_warnings, warnings, migration_warnings].concat()))
+    }
+
+    /// 展开规则中的 extends 和预设，得到完整的转换规则（规则下标保持不变）
+    ///
+    /// 先合并 extends 引用的片段，再展开合并结果中的预设。
+    /// babel-plugin-import 和 modularizeImports 的配置项转换为规则后依次追加在最后。
+    pub fn resolve(self) -> Result<Self, ConfigError> {
+        let definitions = self.definitions.unwrap_or_default();
+        let mut config = self
+            .config
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                let rule = extends::resolve(index, rule, &definitions)?;
+                preset::expand(index, &rule)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (index, options) in self.babel_plugin_import.iter().flatten().enumerate() {
+            config.push(options.to_transform_config(index)?);
+        }
+        for (source, options) in self.modularize_imports.iter().flatten() {
+            config.push(options.to_transform_config(source)?);
+        }
+
+        Ok(PluginConfig {
+            config,
+            definitions: None,
+            babel_plugin_import: None,
+            modularize_imports: None,
+            ..self
+        })
+    }
+
+    /// 验证配置的有效性
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.validated_templates().map(|_| ())
+    }
+
+    /// 验证配置的有效性，返回每条规则解析后的 output 模板
+    pub(crate) fn validated_templates(&self) -> Result<Vec<Vec<Template>>, ConfigError> {
+        let mut templates = Vec::with_capacity(self.config.len());
+        for (index, config) in self.config.iter().enumerate() {
+            // 检查 source 不能为空
+            if config.source.is_empty() {
+                return Err(ConfigError::invalid(
+                    index,
+                    &config.source,
+                    "source",
+                    "'source' must be a non-empty module name.",
+                    "Set 'source' to the module to transform (e.g. \"antd\"),\n\
+                    or use 'preset' to fill in the rule for a supported library.",
+                ));
+            }
+
+            // 检查 output 不能为空数组
+            if config.output.is_empty() {
+                return Err(ConfigError::invalid(
+                    index,
+                    &config.source,
+                    "output",
+                    "'output' must be a non-empty array.",
+                    "The 'output' array defines the import paths to generate:\n\
+                    - First element: main import (with identifier)\n\
+                    - Remaining elements: side-effect imports (e.g., styles)\n\
+                    Example: [\"antd/es/{{ filename }}/index.js\", \"antd/es/{{ filename }}/style/index.css\"]",
+                ));
+            }
+
+            // 检查 include 和 exclude 不能同时存在
+            if config.include.is_some() && config.exclude.is_some() {
+                return Err(ConfigError::invalid(
+                    index,
+                    &config.source,
+                    "exclude",
+                    "'include' and 'exclude' cannot be used together.",
+                    "Please choose one:\n\
+                    - Use 'include' to specify components to process (whitelist)\n\
+                    - Use 'exclude' to specify components to skip (blacklist)",
+                ));
+            }
+
+            // 检查 output 模板的语法
+            let outputs = config
+                .output
+                .iter()
+                .enumerate()
+                .map(|(output_index, output)| {
+                    Template::parse(output).map_err(|error| {
+                        ConfigError::invalid(
+                            index,
+                            &config.source,
+                            &format!("output[{output_index}]"),
+                            format!(
+                                "invalid template '{output}' at column {}: {}.",
+                                error.column, error.message
+                            ),
+                            error.hint.unwrap_or_default(),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // 检查 manifest 是有效的 JSON
+            if let Err(error) = config.known_exports() {
+                return Err(ConfigError::invalid(
+                    index,
+                    &config.source,
+                    "manifest",
+                    format!("'manifest' is not a valid export manifest: {error}."),
+                    "Expected a JSON array of export names (e.g. [\"Button\", \"DatePicker\"])\n\
+                    or a JSON object keyed by export name.",
+                ));
+            }
+
+            templates.push(outputs);
+        }
+        Ok(templates)
+    }
+}
+
+impl TransformConfig {
+    /// 检查组件名称是否匹配当前配置
+    ///
+    /// 匹配规则：
+    /// - 如果配置了 include（白名单），只处理列表中的组件
+    /// - 如果配置了 exclude（黑名单），处理所有组件除了列表中的
+    /// - 如果都没配置，处理所有组件
+    ///
+    /// 注意：include 和 exclude 互斥，不能同时配置
+    pub fn matches(&self, name: &str) -> bool {
+        // 如果配置了 include（白名单），只处理列表中的
+        if let Some(ref include) = self.include {
+            return include.iter().any(|item| item == name);
+        }
+
+        // 如果配置了 exclude（黑名单），排除列表中的
+        if let Some(ref exclude) = self.exclude {
+            return !exclude.iter().any(|item| item == name);
+        }
+
+        // 都没配置，匹配所有组件
+        true
+    }
+
+    /// 根据导入名称生成输出路径中的文件名
+    pub fn filename_for(&self, name: &str) -> String {
+        let name = match &self.strip_prefix {
+            Some(prefix) => name.strip_prefix(prefix.as_str()).unwrap_or(name),
+            None => name,
+        };
+        transform_filename(name, &self.filename.unwrap_or(CURRENT_DEFAULTS.filename))
+    }
+
+    /// 检查当前文件是否禁止整包导入源模块
+    pub fn prevents_full_import(&self, filename: Option<&str>) -> bool {
+        if self.prevent_full_import != Some(true) {
+            return false;
+        }
+
+        match (filename, &self.allow_full_import) {
+            (Some(filename), Some(allow)) => {
+                !allow.iter().any(|pattern| glob_matches(pattern, filename))
+            }
+            _ => true,
+        }
+    }
+
+    /// 合并 exports 和 manifest 得到有效导出名称集合，两者都没配置时返回 None
+    pub fn known_exports(&self) -> Result<Option<HashSet<String>>, String> {
+        if self.exports.is_none() && self.manifest.is_none() {
+            return Ok(None);
+        }
+
+        let mut names: HashSet<String> = self.exports.iter().flatten().cloned().collect();
+
+        if let Some(manifest) = &self.manifest {
+            match serde_json::from_str::<serde_json::Value>(manifest).map_err(|e| e.to_string())? {
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        match value {
+                            serde_json::Value::String(name) => {
+                                names.insert(name);
+                            }
+                            other => return Err(format!("expected a string, found {other}")),
+                        }
+                    }
+                }
+                serde_json::Value::Object(map) => names.extend(map.into_iter().map(|(k, _)| k)),
+                other => return Err(format!("expected an array or object, found {other}")),
+            }
+        }
+
+        Ok(Some(names))
+    }
+}
+
+/// 文件名转换工具函数
+pub fn transform_filename(name: &str, case: &FilenameCase) -> String {
+    match case {
+        FilenameCase::KebabCase => name.to_kebab_case(),
+        FilenameCase::CamelCase => name.to_lower_camel_case(),
+        FilenameCase::SnakeCase => name.to_snake_case(),
+        FilenameCase::PascalCase => name.to_pascal_case(),
+        FilenameCase::LowerCase => name.to_lowercase(),
+        FilenameCase::UpperCase => name.to_uppercase(),
+        FilenameCase::Preserve => name.to_string(),
+        FilenameCase::Camel2Dash => trans_camel(name, '-'),
+        FilenameCase::Camel2Underline => trans_camel(name, '_'),
+    }
+}
+
+/// babel-plugin-import 的 transCamel：首字母小写，其余每个大写字母替换为分隔符加小写字母
+fn trans_camel(name: &str, separator: char) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if index == 0 {
+            result.extend(c.to_lowercase());
+        } else if c.is_ascii_uppercase() {
+            result.push(separator);
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_kebab_case() {
+        assert_eq!("Button".to_kebab_case(), "button");
+        assert_eq!("DatePicker".to_kebab_case(), "date-picker");
+        assert_eq!("MyComponent".to_kebab_case(), "my-component");
+        assert_eq!("XMLHttpRequest".to_kebab_case(), "xml-http-request");
+    }
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!("Button".to_lower_camel_case(), "button");
+        assert_eq!("DatePicker".to_lower_camel_case(), "datePicker");
+        assert_eq!("MyComponent".to_lower_camel_case(), "myComponent");
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!("Button".to_snake_case(), "button");
+        assert_eq!("DatePicker".to_snake_case(), "date_picker");
+        assert_eq!("MyComponent".to_snake_case(), "my_component");
+    }
+
+    #[test]
+    fn test_transform_filename() {
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::KebabCase), "date-picker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::CamelCase), "datePicker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::SnakeCase), "date_picker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::PascalCase), "DatePicker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::LowerCase), "datepicker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::UpperCase), "DATEPICKER");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::Camel2Dash), "date-picker");
+        assert_eq!(transform_filename("DatePicker", &FilenameCase::Camel2Underline), "date_picker");
+    }
+
+    #[test]
+    fn test_transform_filename_like_babel_plugin_import() {
+        // 连续的大写字母逐个分隔，与 kebabCase/snakeCase 不同
+        assert_eq!(transform_filename("QRCode", &FilenameCase::KebabCase), "qr-code");
+        assert_eq!(transform_filename("QRCode", &FilenameCase::Camel2Dash), "q-r-code");
+        assert_eq!(transform_filename("QRCode", &FilenameCase::Camel2Underline), "q_r_code");
+        // 数字和已有的分隔符保持原样
+        assert_eq!(transform_filename("H1Title", &FilenameCase::Camel2Dash), "h1-title");
+        assert_eq!(transform_filename("Foo_Bar", &FilenameCase::Camel2Dash), "foo_-bar");
+    }
+
+    #[test]
+    fn test_config_matches_with_include() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            filename: Some(FilenameCase::KebabCase),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            specifier: Some(SpecifierType::Default),
+            include: Some(vec!["Button".to_string()]),
+            exclude: None,
+            ..Default::default()
+        };
+
+        assert!(config.matches("Button"));
+        assert!(!config.matches("DatePicker"));
+    }
+
+    #[test]
+    fn test_config_matches_with_exclude() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            filename: Some(FilenameCase::KebabCase),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            specifier: Some(SpecifierType::Default),
+            include: None,
+            exclude: Some(vec!["Button".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(!config.matches("Button"));
+        assert!(config.matches("DatePicker"));
+    }
+
+    #[test]
+    fn test_config_matches_without_filters() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            filename: Some(FilenameCase::KebabCase),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            specifier: Some(SpecifierType::Default),
+            include: None,
+            exclude: None,
+            ..Default::default()
+        };
+
+        assert!(config.matches("Button"));
+        assert!(config.matches("DatePicker"));
+        assert!(config.matches("AnyComponent"));
+    }
+
+    #[test]
+    fn test_config_validation_rejects_empty_output() {
+        // 测试验证逻辑：不允许空 output 数组
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec![],
+                specifier: Some(SpecifierType::Default),
+                include: None,
+                exclude: None,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("'output' must be a non-empty array"));
+    }
+
+    #[test]
+    fn test_config_validation_rejects_both_include_and_exclude() {
+        // 测试验证逻辑：不允许同时配置 include 和 exclude
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec!["antd/es/{{ filename }}.js".to_string()],
+                specifier: Some(SpecifierType::Default),
+                include: Some(vec!["Button".to_string()]),
+                exclude: Some(vec!["Table".to_string()]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("'include' and 'exclude' cannot be used together")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_allows_only_include() {
+        // 只有 include，应该通过验证
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec!["antd/es/{{ filename }}.js".to_string()],
+                specifier: Some(SpecifierType::Default),
+                include: Some(vec!["Button".to_string()]),
+                exclude: None,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_allows_only_exclude() {
+        // 只有 exclude，应该通过验证
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec!["antd/es/{{ filename }}.js".to_string()],
+                specifier: Some(SpecifierType::Default),
+                include: None,
+                exclude: Some(vec!["Button".to_string()]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_allows_neither() {
+        // 两个都没有，应该通过验证
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec!["antd/es/{{ filename }}.js".to_string()],
+                specifier: Some(SpecifierType::Default),
+                include: None,
+                exclude: None,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_error_has_path_and_hint() {
+        let config = PluginConfig {
+            config: vec![
+                TransformConfig {
+                    source: "lodash".to_string(),
+                    output: vec!["lodash/{{ filename }}".to_string()],
+                    ..Default::default()
+                },
+                TransformConfig { source: "antd".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        let error = config.validate().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Config #1 (source: 'antd') at `config[1].output`: 'output' must be a non-empty array."
+        );
+        assert!(error.hint().unwrap().contains("side-effect imports"));
+    }
+
+    #[test]
+    fn test_from_json_reports_path_and_position() {
+        let json = r#"{
+  "config": [
+    { "source": "antd", "output": ["antd/es/{{ filename }}"], "filename": "kebab" }
+  ]
+}"#;
+
+        let error = PluginConfig::from_json(json).unwrap_err();
+        match &error {
+            ConfigError::Json { path, line, column, message, .. } => {
+                assert_eq!(path, "config[0].filename");
+                assert_eq!((*line, *column), (3, 81));
+                assert!(message.starts_with("unknown variant `kebab`"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_json_rejects_unknown_fields_with_suggestion() {
+        let json = r#"{ "config": [
+            { "source": "lodash", "output": ["lodash/{{ filename }}"] },
+            { "source": "antd", "outputs": ["antd/es/{{ filename }}"] }
+        ] }"#;
+
+        let error = PluginConfig::from_json(json).unwrap_err();
+        assert_eq!(error.rule_index(), Some(1));
+        assert_eq!(error.hint(), Some("did you mean 'output'?"));
+        assert!(error.to_string().contains("Config #1"));
+        assert!(error.to_string().contains("unknown field `outputs`"));
+
+        let error = PluginConfig::from_json(
+            r#"{ "config": [{ "source": "antd", "output": ["a"], "fileName": "kebabCase" }] }"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.hint(), Some("did you mean 'filename'?"));
+
+        let error = PluginConfig::from_json(r#"{ "configs": [] }"#).unwrap_err();
+        assert_eq!(error.rule_index(), None);
+        assert_eq!(error.hint(), Some("did you mean 'config'?"));
+    }
+
+    #[test]
+    fn test_from_json_validates_config() {
+        let error =
+            PluginConfig::from_json(r#"{ "config": [{ "source": "antd", "output": [] }] }"#)
+                .unwrap_err();
+        assert!(matches!(error, ConfigError::Invalid { index: 0, .. }));
+
+        assert!(
+            PluginConfig::from_json(
+                r#"{ "config": [{ "source": "antd", "output": ["antd/es/{{ filename }}"] }] }"#
+            )
+            .is_ok()
+        );
+
+        // $schema 仅用于编辑器，不会被当作未知字段
+        assert!(PluginConfig::from_json(r#"{ "$schema": "./schema.json", "config": [] }"#).is_ok());
+    }
+
+    #[test]
+    fn test_from_json_expands_presets() {
+        let config =
+            PluginConfig::from_json(r#"{ "config": [{ "preset": "antd@4", "style": "css" }] }"#)
+                .unwrap();
+        assert_eq!(config.config[0].source, "antd");
+        assert_eq!(config.config[0].output.len(), 2);
+        assert_eq!(config.config[0].preset, None);
+
+        let error =
+            PluginConfig::from_json(r#"{ "config": [{ "output": ["lib/{{ filename }}"] }] }"#)
+                .unwrap_err();
+        assert!(error.to_string().contains("'source' must be a non-empty module name"));
+    }
+
+    #[test]
+    fn test_from_json_resolves_extends() {
+        let json = r#"{
+            "definitions": {
+                "antd": { "source": "antd", "filename": "kebabCase", "output": ["antd/es/{{ filename }}"] }
+            },
+            "config": [
+                { "extends": "antd", "include": ["Button"] },
+                { "extends": ["antd"], "source": "antd-mobile", "exclude": ["Toast"] }
+            ]
+        }"#;
+
+        let config = PluginConfig::from_json(json).unwrap();
+        assert_eq!(config.definitions, None);
+        assert_eq!(config.config[0].source, "antd");
+        assert_eq!(config.config[0].include, Some(vec!["Button".to_string()]));
+        assert_eq!(config.config[1].source, "antd-mobile");
+        assert_eq!(config.config[1].output, vec!["antd/es/{{ filename }}".to_string()]);
+    }
+
+    #[test]
+    fn test_from_json_shows_resolved_rule_on_validation_error() {
+        let json = r#"{
+            "definitions": { "antd": { "source": "antd", "exclude": ["message"] } },
+            "config": [{ "extends": "antd", "include": ["Button"], "output": [] }]
+        }"#;
+
+        let error = PluginConfig::from_json(json).unwrap_err();
+        assert!(error.to_string().contains("'output' must be a non-empty array"));
+        assert!(
+            error
+                .hint()
+                .unwrap()
+                .ends_with(r#"Resolved rule: {"include":["Button"],"output":[],"source":"antd"}"#)
+        );
+    }
+
+    #[test]
+    fn test_from_json_translates_babel_plugin_import() {
+        let json = r#"{
+            "config": [{ "source": "lodash", "filename": "preserve", "output": ["lodash/{{ filename }}"] }],
+            "babelPluginImport": [{ "libraryName": "antd", "libraryDirectory": "es", "style": "css" }]
+        }"#;
+
+        let config = PluginConfig::from_json(json).unwrap();
+        assert_eq!(config.config.len(), 2);
+        assert_eq!(config.config[1].source, "antd");
+        assert_eq!(
+            config.config[1].output,
+            vec![
+                "antd/es/{{ filename }}".to_string(),
+                "antd/es/{{ filename }}/style/css".to_string()
+            ]
+        );
+        assert_eq!(config.babel_plugin_import, None);
+
+        let error = PluginConfig::from_json(
+            r#"{ "babelPluginImport": [{ "libraryName": "antd", "libaryDirectory": "es" }] }"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.hint(), Some("did you mean 'libraryDirectory'?"));
+    }
+
+    #[test]
+    fn test_from_json_translates_modularize_imports() {
+        let json = r#"{
+            "modularizeImports": {
+                "lodash": { "transform": "lodash/{{member}}" },
+                "antd": { "transform": "antd/es/{{ kebabCase member }}", "preventFullImport": true }
+            }
+        }"#;
+
+        let config = PluginConfig::from_json(json).unwrap();
+        assert_eq!(config.config.len(), 2);
+        assert_eq!(config.config[0].source, "lodash");
+        assert_eq!(config.config[0].filename, Some(FilenameCase::Preserve));
+        assert_eq!(config.config[1].source, "antd");
+        assert_eq!(config.config[1].output, vec!["antd/es/{{ filename }}".to_string()]);
+        assert_eq!(config.config[1].filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.config[1].prevent_full_import, Some(true));
+        assert_eq!(config.modularize_imports, None);
+    }
+
+    #[test]
+    fn test_load_accepts_modularize_imports_map_as_plugin_options() {
+        // swc_plugin_modularize_imports / Next.js 的配置原样作为插件选项
+        let json = r#"{
+            "lodash": { "transform": "lodash/{{member}}" },
+            "antd": { "transform": "antd/es/{{ kebabCase member }}", "preventFullImport": true },
+            "@mui/icons-material": { "transform": "@mui/icons-material/{{ member }}" }
+        }"#;
+
+        let config = PluginConfig::from_json(json).unwrap();
+        // 保持书写顺序
+        let sources: Vec<&str> = config.config.iter().map(|rule| rule.source.as_str()).collect();
+        assert_eq!(sources, ["lodash", "antd", "@mui/icons-material"]);
+        assert_eq!(config.config[1].filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.config[1].prevent_full_import, Some(true));
+
+        let error = PluginConfig::from_json(
+            r#"{ "antd": { "transform": "antd/es/{{member}}" }, "lodash": { "transfrom": "lodash/{{member}}" } }"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("lodash"));
+        assert!(error.to_string().contains("transfrom"));
+
+        // swc 的 [正则, 模板] 列表给出明确的错误，而不是 untagged 枚举的通用错误
+        let error = PluginConfig::from_json(
+            r#"{ "lib": { "transform": [["^Button$", "lib/button"], ["(.*)", "lib/{{ matches.[1] }}"]] } }"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains(r#"`modularizeImports["lib"].transform`"#));
+        assert!(error.to_string().contains("rules keyed by regular expressions are not supported"));
+
+        // 顶层字段拼写错误仍按插件配置报错
+        let error = PluginConfig::from_json(r#"{ "confg": [] }"#).unwrap_err();
+        assert!(error.to_string().contains("confg"));
+    }
+
+    #[test]
+    fn test_load_reports_rule_analysis_warnings() {
+        let json = r#"{
+            "config": [
+                { "source": "antd", "output": ["antd/es/{{ filename }}"] },
+                { "source": "antd", "include": ["Button"], "output": ["antd/lib/{{ filename }}"] }
+            ]
+        }"#;
+
+        let (config, warnings) = PluginConfig::load(json).unwrap();
+        assert_eq!(config.config.len(), 2);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("Config #1 (source: 'antd') is unreachable"));
+    }
+
+    #[test]
+    fn test_from_json_rejects_invalid_templates() {
+        let error = PluginConfig::from_json(
+            r#"{ "config": [{ "source": "antd", "output": ["antd/es/{{ filename }}", "antd/es/{{ fileName }}/style"] }] }"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Config #0 (source: 'antd') at `config[0].output[1]`: invalid template \
+            'antd/es/{{ fileName }}/style' at column 9: unknown placeholder '{{ fileName }}'."
+        );
+        assert_eq!(error.hint(), Some("did you mean 'filename'?"));
+
+        let error = PluginConfig::from_json(
+            r#"{ "config": [{ "source": "antd", "output": ["antd/es/{{ filename }"] }] }"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("at column 9: unclosed '{{'"));
+    }
+
+    #[test]
+    fn test_config_validation_rejects_invalid_manifest() {
+        let config = PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                output: vec!["antd/es/{{ filename }}.js".to_string()],
+                manifest: Some(r#"["Button", 1]"#.to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result.unwrap_err().to_string().contains("'manifest' is not a valid export manifest")
+        );
+    }
+
+    #[test]
+    fn test_known_exports_merges_exports_and_manifest() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            exports: Some(vec!["Button".to_string()]),
+            manifest: Some(r#"["Table"]"#.to_string()),
+            ..Default::default()
+        };
+
+        let known_exports = config.known_exports().unwrap().unwrap();
+        assert!(known_exports.contains("Button"));
+        assert!(known_exports.contains("Table"));
+        assert_eq!(TransformConfig::default().known_exports(), Ok(None));
+    }
+}
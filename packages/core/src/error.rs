@@ -0,0 +1,182 @@
+use std::fmt;
+
+use crate::config::TransformConfig;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// 插件配置错误
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// 配置 JSON 无法解析或结构不符合要求
+    Json {
+        /// 出错位置的 JSON 路径，如 `config[0].filename`
+        path: String,
+        message: String,
+        line: usize,
+        column: usize,
+        hint: Option<String>,
+    },
+    /// 某条规则的配置项无效
+    Invalid {
+        /// 规则在 config 数组中的下标
+        index: usize,
+        /// 规则的 source
+        source: String,
+        /// 出错的字段路径，如 `config[0].output`
+        path: String,
+        message: String,
+        hint: String,
+    },
+    /// 兼容配置（如 babel-plugin-import 的配置项）无法转换为转换规则
+    Unsupported {
+        /// 出错的配置项路径，如 `babelPluginImport[0].customName`
+        path: String,
+        message: String,
+        hint: String,
+    },
+    /// 宿主没有传入插件配置
+    Missing,
+}
+
+impl ConfigError {
+    /// 创建规则配置项无效的错误
+    pub fn invalid(
+        index: usize,
+        source: &str,
+        field: &str,
+        message: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        ConfigError::Invalid {
+            index,
+            source: source.to_string(),
+            path: format!("config[{index}].{field}"),
+            message: message.into(),
+            hint: hint.into(),
+        }
+    }
+
+    /// 创建兼容配置项无法表示的错误
+    pub fn unsupported(
+        path: impl Into<String>,
+        message: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        ConfigError::Unsupported { path: path.into(), message: message.into(), hint: hint.into() }
+    }
+
+    /// 从 serde 的反序列化错误创建，附带 JSON 路径和行列号
+    pub fn from_json(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = error.path().to_string();
+        let inner = error.into_inner();
+        let (line, column) = (inner.line(), inner.column());
+
+        // serde_json 的错误信息以 " at line X column Y" 结尾，行列号单独保存
+        let message = inner.to_string();
+        let message = message
+            .strip_suffix(&format!(" at line {line} column {column}"))
+            .unwrap_or(&message)
+            .to_string();
+
+        let hint = unknown_field_hint(&message);
+        ConfigError::Json { path, message, line, column, hint }
+    }
+
+    /// 出错规则在 config 数组中的下标，错误不属于某条规则时返回 None
+    pub fn rule_index(&self) -> Option<usize> {
+        match self {
+            ConfigError::Json { path, .. } => {
+                let rest = path.strip_prefix("config[")?;
+                rest[..rest.find(']')?].parse().ok()
+            }
+            ConfigError::Invalid { index, .. } => Some(*index),
+            ConfigError::Unsupported { .. } | ConfigError::Missing => None,
+        }
+    }
+
+    /// 在修复建议中附带展开 extends/preset 之后的完整规则
+    pub fn with_resolved_rule(self, rule: &TransformConfig) -> Self {
+        match self {
+            ConfigError::Invalid { index, source, path, message, hint } => {
+                let hint = format!("{hint}\nResolved rule: {}", resolved_rule_json(rule));
+                ConfigError::Invalid { index, source, path, message, hint }
+            }
+            other => other,
+        }
+    }
+
+    /// 修复建议
+    pub fn hint(&self) -> Option<&str> {
+        match self {
+            ConfigError::Json { hint, .. } => hint.as_deref(),
+            ConfigError::Invalid { hint, .. } | ConfigError::Unsupported { hint, .. } => Some(hint),
+            ConfigError::Missing => Some(
+                "Pass the options as the second element of the plugin entry, \
+                 e.g. [\"@shined/swc-plugin-transform-import-declaration\", { \"config\": [] }].",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Json { path, message, line, column, .. } => {
+                write!(f, "invalid config for transform-import-declaration-plugin")?;
+                if let Some(index) = self.rule_index() {
+                    write!(f, " in Config #{index}")?;
+                }
+                write!(f, " at `{path}` (line {line}, column {column}): {message}")
+            }
+            ConfigError::Invalid { index, source, path, message, .. } => {
+                write!(f, "Config #{index} (source: '{source}') at `{path}`: {message}")
+            }
+            ConfigError::Unsupported { path, message, .. } => {
+                write!(
+                    f,
+                    "unsupported config for transform-import-declaration-plugin at `{path}`: {message}"
+                )
+            }
+            ConfigError::Missing => {
+                write!(f, "missing config for transform-import-declaration-plugin")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// 插件配置警告（配置有效，但可能不符合预期）
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigWarning {
+    /// 相关配置项的路径，如 `config[0].filename`
+    pub path: String,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// 为 serde 的未知字段错误生成 "did you mean" 提示
+///
+/// 错误信息格式为 "unknown field `outputs`, expected one of `source`, `output`, ..."
+/// 或 "unknown field `configs`, expected `config`"。
+fn unknown_field_hint(message: &str) -> Option<String> {
+    let rest = message.strip_prefix("unknown field `")?;
+    let (field, expected) = rest.split_once('`')?;
+    let candidates: Vec<&str> = expected.split('`').skip(1).step_by(2).collect();
+
+    did_you_mean(&closest_matches(field, candidates, 1))
+}
+
+/// 将规则序列化为紧凑的 JSON，省略未配置的字段
+fn resolved_rule_json(rule: &TransformConfig) -> String {
+    let mut value = serde_json::to_value(rule).unwrap_or_default();
+    if let serde_json::Value::Object(map) = &mut value {
+        map.retain(|_, value| !value.is_null());
+    }
+    value.to_string()
+}
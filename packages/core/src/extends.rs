@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use crate::config::TransformConfig;
+use crate::error::ConfigError;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// 展开规则的 extends，按顺序合并引用的片段，规则自身的字段最后合并
+///
+/// 片段也可以通过 extends 引用其他片段，循环引用会报错。
+/// 规则没有按组件的 overrides 和模板变量（输出模板只支持 `{{ filename }}`），因此合并不涉及这两者。
+pub fn resolve(
+    index: usize,
+    rule: &TransformConfig,
+    definitions: &HashMap<String, TransformConfig>,
+) -> Result<TransformConfig, ConfigError> {
+    resolve_with_stack(index, rule, definitions, &mut Vec::new())
+}
+
+fn resolve_with_stack(
+    index: usize,
+    rule: &TransformConfig,
+    definitions: &HashMap<String, TransformConfig>,
+    stack: &mut Vec<String>,
+) -> Result<TransformConfig, ConfigError> {
+    let Some(extends) = &rule.extends else {
+        return Ok(rule.clone());
+    };
+
+    let mut resolved = TransformConfig::default();
+    for name in extends.names() {
+        if stack.contains(name) {
+            let cycle: Vec<&str> = stack.iter().chain(Some(name)).map(String::as_str).collect();
+            return Err(ConfigError::invalid(
+                index,
+                &rule.source,
+                "extends",
+                format!("circular extends: {}.", cycle.join(" -> ")),
+                "Remove one of the references so that definitions form a tree.",
+            ));
+        }
+
+        let Some(fragment) = definitions.get(name) else {
+            let suggestions = closest_matches(name, definitions.keys().map(String::as_str), 3);
+            let hint = did_you_mean(&suggestions).unwrap_or_else(|| {
+                "Add the fragment to the top-level 'definitions' object.".to_string()
+            });
+            return Err(ConfigError::invalid(
+                index,
+                &rule.source,
+                "extends",
+                format!("unknown definition '{name}'."),
+                hint,
+            ));
+        };
+
+        stack.push(name.clone());
+        let fragment = resolve_with_stack(index, fragment, definitions, stack)?;
+        stack.pop();
+
+        check_filter(index, rule, &resolved, &fragment)?;
+        resolved = merge(resolved, &fragment);
+    }
+
+    check_filter(index, rule, &resolved, rule)?;
+    Ok(merge(resolved, rule))
+}
+
+/// 继承了 include 时不允许改用 exclude
+///
+/// exclude 会丢弃继承的 include，使原本只处理少数组件的规则变成处理所有组件，
+/// 这种扩大转换范围的合并多半不是有意的，因此直接报错。
+fn check_filter(
+    index: usize,
+    rule: &TransformConfig,
+    base: &TransformConfig,
+    overlay: &TransformConfig,
+) -> Result<(), ConfigError> {
+    if base.include.is_none() || overlay.include.is_some() || overlay.exclude.is_none() {
+        return Ok(());
+    }
+    Err(ConfigError::invalid(
+        index,
+        &rule.source,
+        "exclude",
+        "'exclude' cannot replace the 'include' list inherited through extends, \
+         that would transform every component the fragment leaves out.",
+        "Remove the names from 'include' instead, or drop 'include' from the fragment.",
+    ))
+}
+
+/// 将 overlay 合并到 base 上
+///
+/// - `output`：overlay 非空时，第一项（主导入）以 overlay 为准，其余副作用导入取并集
+/// - `include`/`exclude`：同类列表取并集；overlay 配置了 include 时，base 的 exclude 被丢弃
+///   （base 的 include 被 overlay 的 exclude 替换会扩大转换范围，由 [`resolve`] 报错）
+/// - `dependencies`：按组件名合并，同名组件以 overlay 为准
+/// - `exports`/`allowFullImport`：取并集
+/// - 其他字段：overlay 配置了就覆盖 base
+pub fn merge(base: TransformConfig, overlay: &TransformConfig) -> TransformConfig {
+    let (include, exclude) = match (&overlay.include, &overlay.exclude) {
+        (None, None) => (base.include, base.exclude),
+        (Some(include), None) => (Some(union(base.include, include)), None),
+        (None, Some(exclude)) => (None, Some(union(base.exclude, exclude))),
+        // 两者同时存在是无效配置，保留原样交给 validate 报告
+        (include, exclude) => (include.clone(), exclude.clone()),
+    };
+
+    let dependencies = match (base.dependencies, &overlay.dependencies) {
+        (Some(mut dependencies), Some(overlay)) => {
+            dependencies.extend(overlay.iter().map(|(k, v)| (k.clone(), v.clone())));
+            Some(dependencies)
+        }
+        (base, overlay) => overlay.clone().or(base),
+    };
+
+    TransformConfig {
+        extends: None,
+        preset: overlay.preset.clone().or(base.preset),
+        style: overlay.style.or(base.style),
+        source: if overlay.source.is_empty() { base.source } else { overlay.source.clone() },
+        output: merge_output(base.output, &overlay.output),
+        filename: overlay.filename.or(base.filename),
+        strip_prefix: overlay.strip_prefix.clone().or(base.strip_prefix),
+        specifier: overlay.specifier.or(base.specifier),
+        include,
+        exclude,
+        dependencies,
+        exports: merge_list(base.exports, &overlay.exports),
+        manifest: overlay.manifest.clone().or(base.manifest),
+        on_unknown_export: overlay.on_unknown_export.or(base.on_unknown_export),
+        on_unmatched: overlay.on_unmatched.or(base.on_unmatched),
+        prevent_full_import: overlay.prevent_full_import.or(base.prevent_full_import),
+        allow_full_import: merge_list(base.allow_full_import, &overlay.allow_full_import),
+    }
+}
+
+fn merge_output(base: Vec<String>, overlay: &[String]) -> Vec<String> {
+    let Some((main, side_effects)) = overlay.split_first() else {
+        return base;
+    };
+    let mut output = vec![main.clone()];
+    for path in base.into_iter().skip(1).chain(side_effects.iter().cloned()) {
+        if !output.contains(&path) {
+            output.push(path);
+        }
+    }
+    output
+}
+
+fn merge_list(base: Option<Vec<String>>, overlay: &Option<Vec<String>>) -> Option<Vec<String>> {
+    match overlay {
+        Some(overlay) => Some(union(base, overlay)),
+        None => base,
+    }
+}
+
+/// 合并两个列表，保持顺序并去掉重复项
+fn union(base: Option<Vec<String>>, overlay: &[String]) -> Vec<String> {
+    let mut merged = base.unwrap_or_default();
+    for item in overlay {
+        if !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Extends, FilenameCase, SpecifierType};
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn definitions() -> HashMap<String, TransformConfig> {
+        HashMap::from([
+            (
+                "antd".to_string(),
+                TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: strings(&["antd/es/{{ filename }}"]),
+                    exclude: Some(strings(&["message"])),
+                    exports: Some(strings(&["Button", "message"])),
+                    ..Default::default()
+                },
+            ),
+            (
+                "antd-css".to_string(),
+                TransformConfig {
+                    extends: Some(Extends::One("antd".to_string())),
+                    output: strings(&[
+                        "antd/es/{{ filename }}",
+                        "antd/es/{{ filename }}/style/css",
+                    ]),
+                    ..Default::default()
+                },
+            ),
+            (
+                "strict".to_string(),
+                TransformConfig { prevent_full_import: Some(true), ..Default::default() },
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_rule_without_extends_is_unchanged() {
+        let rule = TransformConfig { source: "lodash".to_string(), ..Default::default() };
+        assert_eq!(resolve(0, &rule, &definitions()).unwrap(), rule);
+    }
+
+    #[test]
+    fn test_extends_merges_fragments_in_order() {
+        let rule = TransformConfig {
+            extends: Some(Extends::Many(strings(&["antd-css", "strict"]))),
+            specifier: Some(SpecifierType::Named),
+            exclude: Some(strings(&["notification"])),
+            exports: Some(strings(&["notification"])),
+            ..Default::default()
+        };
+
+        let resolved = resolve(0, &rule, &definitions()).unwrap();
+        assert_eq!(resolved.extends, None);
+        assert_eq!(resolved.source, "antd");
+        assert_eq!(
+            resolved.output,
+            strings(&["antd/es/{{ filename }}", "antd/es/{{ filename }}/style/css"])
+        );
+        assert_eq!(resolved.filename, Some(FilenameCase::KebabCase));
+        assert_eq!(resolved.specifier, Some(SpecifierType::Named));
+        assert_eq!(resolved.exclude, Some(strings(&["message", "notification"])));
+        assert_eq!(resolved.exports, Some(strings(&["Button", "message", "notification"])));
+        assert_eq!(resolved.prevent_full_import, Some(true));
+    }
+
+    #[test]
+    fn test_include_replaces_inherited_exclude() {
+        let rule = TransformConfig {
+            extends: Some(Extends::One("antd".to_string())),
+            include: Some(strings(&["Button"])),
+            ..Default::default()
+        };
+
+        let resolved = resolve(0, &rule, &definitions()).unwrap();
+        assert_eq!(resolved.include, Some(strings(&["Button"])));
+        assert_eq!(resolved.exclude, None);
+    }
+
+    #[test]
+    fn test_output_merges_side_effect_imports() {
+        let base = TransformConfig {
+            output: strings(&["antd/es/{{ filename }}", "antd/es/{{ filename }}/style/css"]),
+            ..Default::default()
+        };
+
+        // 只覆盖主导入时保留继承的副作用导入
+        let overlay =
+            TransformConfig { output: strings(&["antd/lib/{{ filename }}"]), ..Default::default() };
+        assert_eq!(
+            merge(base.clone(), &overlay).output,
+            strings(&["antd/lib/{{ filename }}", "antd/es/{{ filename }}/style/css"])
+        );
+
+        // 副作用导入取并集
+        let overlay = TransformConfig {
+            output: strings(&["antd/es/{{ filename }}", "antd/es/{{ filename }}/locale"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            merge(base.clone(), &overlay).output,
+            strings(&[
+                "antd/es/{{ filename }}",
+                "antd/es/{{ filename }}/style/css",
+                "antd/es/{{ filename }}/locale"
+            ])
+        );
+
+        assert_eq!(merge(base.clone(), &TransformConfig::default()).output, base.output);
+    }
+
+    #[test]
+    fn test_exclude_cannot_replace_inherited_include() {
+        let mut definitions = definitions();
+        definitions.insert(
+            "buttons".to_string(),
+            TransformConfig {
+                extends: Some(Extends::One("antd".to_string())),
+                include: Some(strings(&["Button"])),
+                ..Default::default()
+            },
+        );
+
+        let rule = TransformConfig {
+            extends: Some(Extends::One("buttons".to_string())),
+            exclude: Some(strings(&["Button"])),
+            ..Default::default()
+        };
+        let error = resolve(2, &rule, &definitions).unwrap_err();
+        assert!(error.to_string().contains("Config #2"));
+        assert!(error.to_string().contains("config[2].exclude"));
+        assert!(error.to_string().contains("cannot replace the 'include' list"));
+
+        // 片段之间同样不允许
+        definitions.insert(
+            "no-button".to_string(),
+            TransformConfig { exclude: Some(strings(&["Button"])), ..Default::default() },
+        );
+        let rule = TransformConfig {
+            extends: Some(Extends::Many(strings(&["buttons", "no-button"]))),
+            ..Default::default()
+        };
+        assert!(resolve(0, &rule, &definitions).is_err());
+    }
+
+    #[test]
+    fn test_dependencies_merge_per_component() {
+        let base = TransformConfig {
+            dependencies: Some(HashMap::from([
+                ("Table".to_string(), strings(&["Pagination"])),
+                ("Modal".to_string(), strings(&["Button"])),
+            ])),
+            ..Default::default()
+        };
+        let overlay = TransformConfig {
+            dependencies: Some(HashMap::from([("Modal".to_string(), strings(&[]))])),
+            ..Default::default()
+        };
+
+        let dependencies = merge(base, &overlay).dependencies.unwrap();
+        assert_eq!(dependencies["Table"], strings(&["Pagination"]));
+        assert!(dependencies["Modal"].is_empty());
+    }
+
+    #[test]
+    fn test_unknown_definition_suggests_name() {
+        let rule = TransformConfig {
+            extends: Some(Extends::One("antd-cs".to_string())),
+            ..Default::default()
+        };
+
+        let error = resolve(1, &rule, &definitions()).unwrap_err();
+        assert!(error.to_string().contains("Config #1"));
+        assert!(error.to_string().contains("unknown definition 'antd-cs'"));
+        assert_eq!(error.hint(), Some("did you mean 'antd-css'?"));
+    }
+
+    #[test]
+    fn test_circular_extends() {
+        let mut definitions = definitions();
+        definitions.get_mut("antd").unwrap().extends = Some(Extends::One("antd-css".to_string()));
+        let rule = TransformConfig {
+            extends: Some(Extends::One("antd-css".to_string())),
+            ..Default::default()
+        };
+
+        let error = resolve(0, &rule, &definitions).unwrap_err();
+        assert!(error.to_string().contains("circular extends: antd-css -> antd -> antd-css"));
+    }
+}
@@ -0,0 +1,73 @@
+/// 检查文件路径是否匹配 glob 模式
+///
+/// 支持的语法：
+/// - `*` 匹配除 `/` 以外的任意字符
+/// - `**` 匹配任意字符（包括 `/`），`**/` 可以匹配零层目录
+/// - `?` 匹配除 `/` 以外的单个字符
+///
+/// 路径中的 `\` 会被视为 `/`。不以 `/` 或 `**` 开头的模式可以从路径的任意一层目录开始匹配，
+/// 例如 `src/legacy/**` 可以匹配 `/home/app/src/legacy/index.tsx`。
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.replace('\\', "/");
+    let path = path.replace('\\', "/");
+
+    let pattern = pattern.strip_prefix("./").unwrap_or(&pattern);
+    let pattern: Vec<char> = if pattern.starts_with('/') || pattern.starts_with("**") {
+        pattern.chars().collect()
+    } else {
+        "**/".chars().chain(pattern.chars()).collect()
+    };
+    let path: Vec<char> = path.chars().collect();
+
+    matches_from(&pattern, &path)
+}
+
+fn matches_from(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            // `**/` 匹配零层或多层目录
+            matches_from(rest, path)
+                || (0..path.len())
+                    .filter(|&i| path[i] == '/')
+                    .any(|i| matches_from(rest, &path[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|i| matches_from(rest, &path[i..])),
+        ['*', rest @ ..] => {
+            let segment_end = path.iter().position(|&c| c == '/').unwrap_or(path.len());
+            (0..=segment_end).any(|i| matches_from(rest, &path[i..]))
+        }
+        ['?', rest @ ..] => {
+            matches!(path.first(), Some(&c) if c != '/') && matches_from(rest, &path[1..])
+        }
+        [c, rest @ ..] => path.first() == Some(c) && matches_from(rest, &path[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches_single_star() {
+        assert!(glob_matches("src/*.ts", "src/index.ts"));
+        assert!(!glob_matches("src/*.ts", "src/nested/index.ts"));
+        assert!(glob_matches("src/?.ts", "src/a.ts"));
+    }
+
+    #[test]
+    fn test_glob_matches_double_star() {
+        assert!(glob_matches("src/legacy/**", "src/legacy/pages/home.tsx"));
+        assert!(glob_matches("src/**/*.stories.tsx", "src/button.stories.tsx"));
+        assert!(glob_matches("src/**/*.stories.tsx", "src/components/button.stories.tsx"));
+        assert!(!glob_matches("src/**/*.stories.tsx", "src/components/button.tsx"));
+    }
+
+    #[test]
+    fn test_glob_matches_relative_pattern_at_any_directory() {
+        assert!(glob_matches("src/legacy/**", "/home/app/src/legacy/index.tsx"));
+        assert!(glob_matches("./src/legacy/**", "C:\\app\\src\\legacy\\index.tsx"));
+        assert!(!glob_matches("/src/legacy/**", "/home/app/src/legacy/index.tsx"));
+        assert!(!glob_matches("src/legacy/**", "/home/app/mysrc/legacy/index.tsx"));
+    }
+}
@@ -0,0 +1,41 @@
+//! 导入声明转换规则的核心实现，不依赖任何 JavaScript 编译器
+//!
+//! 包含配置的解析、验证和编译，以及给定源模块和导入名称时应该生成哪些导入的解析逻辑。
+//! swc 插件在此基础上实现 AST 转换，其他工具（lint、导入分析等）可以直接复用这些规则：
+//!
+//! ```
+//! use transform_import_declaration_core::compile::CompiledConfig;
+//! use transform_import_declaration_core::config::PluginConfig;
+//! use transform_import_declaration_core::resolve::{Resolution, ResolvedImport};
+//!
+//! let config = PluginConfig::from_json(
+//!     r#"{ "config": [{ "source": "antd", "filename": "kebabCase", "output": ["antd/es/{{ filename }}"] }] }"#,
+//! )
+//! .unwrap();
+//! let compiled = CompiledConfig::new(config).unwrap();
+//!
+//! let Resolution::Resolved { imports, .. } = compiled.resolve("antd", "DatePicker") else {
+//!     unreachable!();
+//! };
+//! assert_eq!(imports[0].path(), "antd/es/date-picker");
+//! assert!(matches!(imports[0], ResolvedImport::Main { .. }));
+//! ```
+
+mod analysis;
+pub mod babel_plugin_import;
+pub mod builder;
+pub mod cache;
+pub mod compile;
+pub mod config;
+pub mod error;
+mod extends;
+mod glob;
+pub mod modularize_imports;
+mod preset;
+pub mod report;
+pub mod resolve;
+#[cfg(test)]
+mod schema;
+pub mod suggest;
+pub mod template;
+pub mod version;
@@ -0,0 +1,282 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::config::{FilenameCase, SpecifierType, TransformConfig};
+use crate::error::ConfigError;
+
+/// modularizeImports 的 transform 选项
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(untagged)]
+pub enum ModularizeImportsTransform {
+    /// 路径模板，如 `lodash/{{ member }}`
+    Template(String),
+    /// swc 格式的 `[正则, 模板]` 列表（不支持，仅用于给出明确的错误信息）
+    Pairs(Vec<(String, String)>),
+    /// Next.js 格式的 正则 -> 模板 映射（不支持，仅用于给出明确的错误信息）
+    Map(IndexMap<String, String>),
+}
+
+/// swc/Next.js modularizeImports 的单个包配置，用于迁移
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema, ts_rs::TS))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[cfg_attr(test, ts(optional_fields))]
+pub struct ModularizeImportsOptions {
+    /// 导入路径模板，支持 `{{ member }}` 以及 swc 的 `camelCase`、`kebabCase`、`lowerCase`、`upperCase` helper
+    pub transform: ModularizeImportsTransform,
+    /// 禁止整包导入，对应 preventFullImport
+    #[serde(default)]
+    pub prevent_full_import: Option<bool>,
+    /// 使用命名导入而不是默认导入
+    #[serde(default)]
+    pub skip_default_conversion: Option<bool>,
+}
+
+/// 插件配置的顶层字段，插件选项包含其中任何一个时不视为 modularizeImports 格式
+const PLUGIN_CONFIG_FIELDS: &[&str] =
+    &["$schema", "version", "definitions", "config", "babelPluginImport", "modularizeImports"];
+
+/// 插件选项是否直接是 swc/Next.js 的 modularizeImports 配置（包名 -> 配置）
+///
+/// 顶层不包含插件配置的字段、每个值都是对象且至少有一个包含 transform 时视为这种格式，
+/// 这样从 swc_plugin_modularize_imports 切换过来时配置无需改写。
+/// 没有任何 transform 的对象（如拼错的 `definitons`）按插件配置解析，报告未知字段。
+pub fn is_package_map(json: &str) -> bool {
+    let Ok(serde_json::Value::Object(options)) = serde_json::from_str(json) else {
+        return false;
+    };
+    options.values().any(|value| value.get("transform").is_some())
+        && options
+            .iter()
+            .all(|(key, value)| !PLUGIN_CONFIG_FIELDS.contains(&key.as_str()) && value.is_object())
+}
+
+/// 正则表达式中的特殊字符，包名中出现时说明使用了正则匹配
+const REGEX_CHARACTERS: &[char] = &['(', ')', '[', ']', '*', '+', '?', '|', '^', '$', '\\'];
+
+impl ModularizeImportsOptions {
+    /// 转换为等价的转换规则，无法表示的配置返回错误
+    pub fn to_transform_config(&self, source: &str) -> Result<TransformConfig, ConfigError> {
+        let path = |field: &str| format!("modularizeImports[\"{source}\"]{field}");
+
+        if source.contains(REGEX_CHARACTERS) {
+            return Err(ConfigError::unsupported(
+                path(""),
+                format!("package pattern '{source}' is a regular expression."),
+                "Only exact package names are supported, add one entry per package.",
+            ));
+        }
+
+        let template = match &self.transform {
+            ModularizeImportsTransform::Template(template) => template,
+            ModularizeImportsTransform::Pairs(_) | ModularizeImportsTransform::Map(_) => {
+                return Err(ConfigError::unsupported(
+                    path(".transform"),
+                    "'transform' rules keyed by regular expressions are not supported.",
+                    "Use a single template string, or split the members into rules with 'include' \
+                    in 'config'.",
+                ));
+            }
+        };
+
+        let (output, filename) = convert_template(template).map_err(|(message, hint)| {
+            ConfigError::unsupported(path(".transform"), message, hint)
+        })?;
+
+        let specifier = if self.skip_default_conversion == Some(true) {
+            SpecifierType::Named
+        } else {
+            SpecifierType::Default
+        };
+
+        Ok(TransformConfig {
+            source: source.to_string(),
+            output: vec![output],
+            filename: Some(filename),
+            specifier: Some(specifier),
+            prevent_full_import: self.prevent_full_import,
+            ..Default::default()
+        })
+    }
+}
+
+/// 将 handlebars 风格的模板转换为 `{{ filename }}` 模板和对应的文件名转换规则
+///
+/// 所有占位符必须使用同一个 helper，因为一条规则只有一种文件名转换规则。
+fn convert_template(template: &str) -> Result<(String, FilenameCase), (String, String)> {
+    let mut output = String::new();
+    let mut filename: Option<(&str, FilenameCase)> = None;
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            return Err((
+                format!("template '{template}' has an unclosed placeholder '{}'.", &rest[start..]),
+                "Close the placeholder with '}}', e.g. '{{ member }}'.".to_string(),
+            ));
+        };
+        let expression = rest[start + 2..start + end].trim();
+        let case = helper_case(expression)?;
+
+        match filename {
+            Some((previous, previous_case)) if previous_case != case => {
+                return Err((
+                    format!(
+                        "template '{template}' uses different helpers '{{{{ {previous} }}}}' \
+                        and '{{{{ {expression} }}}}'."
+                    ),
+                    "A rule has a single filename case, use the same helper for every placeholder."
+                        .to_string(),
+                ));
+            }
+            Some(_) => {}
+            None => filename = Some((expression, case)),
+        }
+
+        output.push_str(&rest[..start]);
+        output.push_str("{{ filename }}");
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+
+    Ok((output, filename.map(|(_, case)| case).unwrap_or(FilenameCase::Preserve)))
+}
+
+/// 解析占位符表达式（如 `member`、`kebabCase member`）对应的文件名转换规则
+fn helper_case(expression: &str) -> Result<FilenameCase, (String, String)> {
+    let words: Vec<&str> = expression.split_whitespace().collect();
+    let helper = match words.as_slice() {
+        ["member"] => return Ok(FilenameCase::Preserve),
+        [helper, "member"] => *helper,
+        _ => {
+            return Err((
+                format!("placeholder '{{{{ {expression} }}}}' is not supported."),
+                "Only '{{ member }}' and '{{ <helper> member }}' are supported; \
+                regular expression captures such as '{{ matches.[1] }}' are not."
+                    .to_string(),
+            ));
+        }
+    };
+
+    // 与 swc_plugin_modularize_imports 注册的 helper 保持一致
+    match helper {
+        "camelCase" => Ok(FilenameCase::CamelCase),
+        "kebabCase" => Ok(FilenameCase::KebabCase),
+        "lowerCase" => Ok(FilenameCase::LowerCase),
+        "upperCase" => Ok(FilenameCase::UpperCase),
+        _ => Err((
+            format!("helper '{helper}' is not supported."),
+            "Supported helpers: camelCase, kebabCase, lowerCase, upperCase.".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(template: &str) -> ModularizeImportsOptions {
+        ModularizeImportsOptions {
+            transform: ModularizeImportsTransform::Template(template.to_string()),
+            prevent_full_import: None,
+            skip_default_conversion: None,
+        }
+    }
+
+    #[test]
+    fn test_member_template() {
+        let config = options("lodash/{{member}}").to_transform_config("lodash").unwrap();
+        assert_eq!(config.source, "lodash");
+        assert_eq!(config.output, vec!["lodash/{{ filename }}"]);
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+        assert_eq!(config.specifier, Some(SpecifierType::Default));
+    }
+
+    #[test]
+    fn test_helper_templates() {
+        let config = options("antd/es/{{ kebabCase member }}/index.js/{{kebabCase member}}")
+            .to_transform_config("antd")
+            .unwrap();
+        assert_eq!(config.output, vec!["antd/es/{{ filename }}/index.js/{{ filename }}"]);
+        assert_eq!(config.filename, Some(FilenameCase::KebabCase));
+
+        let config = options("@acme/ui/{{ camelCase member }}").to_transform_config("@acme/ui");
+        assert_eq!(config.unwrap().filename, Some(FilenameCase::CamelCase));
+        let config = options("@acme/ui/{{ lowerCase member }}").to_transform_config("@acme/ui");
+        assert_eq!(config.unwrap().filename, Some(FilenameCase::LowerCase));
+        let config = options("@acme/ui/{{ upperCase member }}").to_transform_config("@acme/ui");
+        assert_eq!(config.unwrap().filename, Some(FilenameCase::UpperCase));
+    }
+
+    #[test]
+    fn test_flags() {
+        let config = ModularizeImportsOptions {
+            prevent_full_import: Some(true),
+            skip_default_conversion: Some(true),
+            ..options("date-fns/{{ member }}")
+        }
+        .to_transform_config("date-fns")
+        .unwrap();
+        assert_eq!(config.specifier, Some(SpecifierType::Named));
+        assert_eq!(config.prevent_full_import, Some(true));
+    }
+
+    #[test]
+    fn test_rejects_unrepresentable_configs() {
+        // swc 没有 snakeCase/pascalCase helper
+        let error = options("lib/{{ snakeCase member }}").to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("`modularizeImports[\"lib\"].transform`"));
+        assert!(error.to_string().contains("helper 'snakeCase' is not supported"));
+        let error = options("lib/{{ pascalCase member }}").to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("helper 'pascalCase' is not supported"));
+
+        let error = options("lib/{{ kebabCase member }}/{{ member }}")
+            .to_transform_config("lib")
+            .unwrap_err();
+        assert!(error.to_string().contains("uses different helpers"));
+
+        let error = options("lib/{{ member }}/{{ member").to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("`modularizeImports[\"lib\"].transform`"));
+        assert!(error.to_string().contains("has an unclosed placeholder '{{ member'"));
+
+        let error =
+            options("lib/{{ matches.[1] }}/{{ member }}").to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("placeholder '{{ matches.[1] }}' is not supported"));
+
+        let error = options("lib/{{ member }}").to_transform_config("lib/?(.*)").unwrap_err();
+        assert!(error.to_string().contains("is a regular expression"));
+
+        // swc 格式：[正则, 模板] 列表
+        let options: ModularizeImportsOptions = serde_json::from_str(
+            r#"{ "transform": [["^Button$", "lib/button"], ["(.*)", "lib/{{ matches.[1] }}"]] }"#,
+        )
+        .unwrap();
+        let error = options.to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("rules keyed by regular expressions"));
+
+        // Next.js 格式：正则 -> 模板 映射
+        let options: ModularizeImportsOptions =
+            serde_json::from_str(r#"{ "transform": { "^Button$": "lib/button" } }"#).unwrap();
+        let error = options.to_transform_config("lib").unwrap_err();
+        assert!(error.to_string().contains("rules keyed by regular expressions"));
+    }
+
+    #[test]
+    fn test_is_package_map() {
+        assert!(is_package_map(r#"{ "lodash": { "transform": "lodash/{{member}}" } }"#));
+        assert!(!is_package_map(r#"{ "config": [] }"#));
+        assert!(!is_package_map(r#"{ "modularizeImports": {} }"#));
+        assert!(!is_package_map(r#"{ "confg": [] }"#));
+        assert!(!is_package_map(r#"{ "lodash": {} }"#));
+        assert!(is_package_map(r#"{ "lodash": { "transform": "" }, "antd": {} }"#));
+
+        // 拼错的插件配置字段报告为未知字段，而不是 modularizeImports 的错误
+        let json = r#"{ "definitons": { "antd": { "source": "antd" } } }"#;
+        assert!(!is_package_map(json));
+        let error = crate::config::PluginConfig::load(json).unwrap_err();
+        assert!(error.to_string().contains("unknown field `definitons`"), "{error}");
+        assert!(!is_package_map("{}"));
+        assert!(!is_package_map("[]"));
+    }
+}
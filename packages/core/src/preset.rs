@@ -0,0 +1,451 @@
+use crate::config::{FilenameCase, PluginConfig, PresetStyle, SpecifierType, TransformConfig};
+use crate::error::{ConfigError, ConfigWarning};
+use crate::extends;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// 内置预设名称（不带版本号的名称指向最新的主版本）
+pub const PRESETS: &[&str] = &[
+    "antd",
+    "antd@4",
+    "antd@5",
+    "lodash",
+    "lodash-es",
+    "@mui/material",
+    "@mui/icons-material",
+    "@arco-design/web-react",
+    "element-plus",
+    "date-fns",
+    "date-fns@2",
+    "date-fns@3",
+    "@ant-design/icons",
+];
+
+/// `@mui/material` 中由 `@mui/material/styles` 提供的导出，不存在同名的子路径
+const MUI_STYLES_EXPORTS: &[&str] = &[
+    "ThemeProvider",
+    "StyledEngineProvider",
+    "createTheme",
+    "styled",
+    "useTheme",
+    "alpha",
+    "darken",
+    "lighten",
+    "emphasize",
+    "getContrastRatio",
+    "responsiveFontSizes",
+];
+
+/// `@ant-design/icons` 中不是图标组件的导出
+const ANT_DESIGN_ICONS_UTILS: &[&str] =
+    &["createFromIconfontCN", "getTwoToneColor", "setTwoToneColor", "IconProvider"];
+
+/// 将带有 preset 的规则展开为完整的转换规则
+///
+/// 预设提供 source、output、filename、specifier 等字段，规则中显式配置的字段覆盖预设的值。
+/// 规则中配置了 include 或 exclude 时，预设的 include/exclude 会被整体替换。
+pub fn expand(index: usize, rule: &TransformConfig) -> Result<TransformConfig, ConfigError> {
+    let Some(name) = rule.preset.as_deref() else {
+        if rule.style.is_some() {
+            return Err(ConfigError::invalid(
+                index,
+                &rule.source,
+                "style",
+                "'style' can only be used together with 'preset'.",
+                "Add the style file paths to 'output' instead, e.g. \
+                [\"antd/es/{{ filename }}\", \"antd/es/{{ filename }}/style/css\"]",
+            ));
+        }
+        return Ok(rule.clone());
+    };
+
+    let base = preset_rule(name, rule.style).map_err(|(field, message, hint)| {
+        let source = if rule.source.is_empty() { name } else { &rule.source };
+        ConfigError::invalid(index, source, field, message, hint)
+    })?;
+
+    let (include, exclude) = if rule.include.is_some() || rule.exclude.is_some() {
+        (rule.include.clone(), rule.exclude.clone())
+    } else {
+        (base.include, base.exclude)
+    };
+
+    Ok(TransformConfig {
+        source: if rule.source.is_empty() { base.source } else { rule.source.clone() },
+        output: if rule.output.is_empty() { base.output } else { rule.output.clone() },
+        filename: rule.filename.or(base.filename),
+        specifier: rule.specifier.or(base.specifier),
+        include,
+        exclude,
+        strip_prefix: rule.strip_prefix.clone().or(base.strip_prefix),
+        preset: None,
+        style: None,
+        ..rule.clone()
+    })
+}
+
+/// 检查被预设接受但不产生任何效果的配置，如 antd@5 的 `style: "css"`
+pub fn warnings(config: &PluginConfig) -> Vec<ConfigWarning> {
+    let definitions = config.definitions.clone().unwrap_or_default();
+    config
+        .config
+        .iter()
+        .enumerate()
+        .filter_map(|(index, rule)| {
+            // extends 的错误由 resolve 报告
+            let rule = extends::resolve(index, rule, &definitions).ok()?;
+            match (rule.preset.as_deref()?, rule.style?) {
+                (name @ ("antd" | "antd@5"), PresetStyle::Css) => Some(ConfigWarning {
+                    path: format!("config[{index}].style"),
+                    message: format!(
+                        "Config #{index} (preset: '{name}'): antd 5 styles components with \
+                        CSS-in-JS, 'style': 'css' adds no style imports."
+                    ),
+                    hint: Some(
+                        "Remove 'style'. If you need the reset stylesheet, import \
+                        'antd/dist/reset.css' once in your app entry."
+                            .to_string(),
+                    ),
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// 预设展开失败：(字段, 错误信息, 修复建议)
+type PresetError = (&'static str, String, String);
+
+/// 根据预设名称和样式生成规则
+fn preset_rule(name: &str, style: Option<PresetStyle>) -> Result<TransformConfig, PresetError> {
+    let rule = |source: &str, filename, specifier, output: &[String]| TransformConfig {
+        source: source.to_string(),
+        output: output.to_vec(),
+        filename: Some(filename),
+        specifier: Some(specifier),
+        ..Default::default()
+    };
+    let with_style = |main: String, styles: &[(PresetStyle, &str)]| match style {
+        None => Ok(vec![main]),
+        Some(style) => match styles.iter().find(|(supported, _)| *supported == style) {
+            Some((_, path)) => Ok(vec![main, path.to_string()]),
+            None => Err(unsupported_style(name, style, styles)),
+        },
+    };
+
+    let config = match name {
+        "antd@4" => rule(
+            "antd",
+            FilenameCase::KebabCase,
+            SpecifierType::Default,
+            &with_style(
+                "antd/es/{{ filename }}".to_string(),
+                &[
+                    (PresetStyle::Css, "antd/es/{{ filename }}/style/css"),
+                    (PresetStyle::Less, "antd/es/{{ filename }}/style"),
+                ],
+            )?,
+        ),
+        // antd@5 使用 CSS-in-JS，没有按组件拆分的样式文件；style: "css" 不生成样式导入（见 warnings）
+        "antd" | "antd@5" => rule(
+            "antd",
+            FilenameCase::KebabCase,
+            SpecifierType::Default,
+            &match style {
+                Some(PresetStyle::Css) => vec!["antd/es/{{ filename }}".to_string()],
+                _ => with_style("antd/es/{{ filename }}".to_string(), &[])?,
+            },
+        ),
+        "lodash" | "lodash-es" => rule(
+            name,
+            FilenameCase::Preserve,
+            SpecifierType::Default,
+            &with_style(format!("{name}/{{{{ filename }}}}"), &[])?,
+        ),
+        "@mui/material" => TransformConfig {
+            exclude: Some(MUI_STYLES_EXPORTS.iter().map(|s| s.to_string()).collect()),
+            ..rule(
+                name,
+                FilenameCase::Preserve,
+                SpecifierType::Default,
+                &with_style("@mui/material/{{ filename }}".to_string(), &[])?,
+            )
+        },
+        "@mui/icons-material" => rule(
+            name,
+            FilenameCase::Preserve,
+            SpecifierType::Default,
+            &with_style("@mui/icons-material/{{ filename }}".to_string(), &[])?,
+        ),
+        "@arco-design/web-react" => rule(
+            name,
+            FilenameCase::Preserve,
+            SpecifierType::Default,
+            &with_style(
+                "@arco-design/web-react/es/{{ filename }}".to_string(),
+                &[
+                    (PresetStyle::Css, "@arco-design/web-react/es/{{ filename }}/style/css.js"),
+                    (PresetStyle::Less, "@arco-design/web-react/es/{{ filename }}/style/index.js"),
+                ],
+            )?,
+        ),
+        "element-plus" => TransformConfig {
+            strip_prefix: Some("El".to_string()),
+            ..rule(
+                name,
+                FilenameCase::KebabCase,
+                SpecifierType::Named,
+                &with_style(
+                    "element-plus/es/components/{{ filename }}/index.mjs".to_string(),
+                    &[
+                        (
+                            PresetStyle::Css,
+                            "element-plus/es/components/{{ filename }}/style/css.mjs",
+                        ),
+                        (
+                            PresetStyle::Scss,
+                            "element-plus/es/components/{{ filename }}/style/index.mjs",
+                        ),
+                    ],
+                )?,
+            )
+        },
+        "date-fns" | "date-fns@3" => rule(
+            "date-fns",
+            FilenameCase::Preserve,
+            SpecifierType::Named,
+            &with_style("date-fns/{{ filename }}".to_string(), &[])?,
+        ),
+        "date-fns@2" => rule(
+            "date-fns",
+            FilenameCase::Preserve,
+            SpecifierType::Default,
+            &with_style("date-fns/{{ filename }}".to_string(), &[])?,
+        ),
+        "@ant-design/icons" => TransformConfig {
+            exclude: Some(ANT_DESIGN_ICONS_UTILS.iter().map(|s| s.to_string()).collect()),
+            ..rule(
+                name,
+                FilenameCase::Preserve,
+                SpecifierType::Default,
+                &with_style("@ant-design/icons/es/icons/{{ filename }}".to_string(), &[])?,
+            )
+        },
+        _ => {
+            let suggestions = closest_matches(name, PRESETS.iter().copied(), 3);
+            return Err((
+                "preset",
+                format!("unknown preset '{name}'."),
+                did_you_mean(&suggestions)
+                    .unwrap_or_else(|| format!("Available presets: {}", PRESETS.join(", "))),
+            ));
+        }
+    };
+
+    Ok(config)
+}
+
+fn unsupported_style(
+    name: &str,
+    style: PresetStyle,
+    styles: &[(PresetStyle, &str)],
+) -> PresetError {
+    let hint = if styles.is_empty() {
+        format!("Preset '{name}' has no per-component style files, remove 'style'.")
+    } else {
+        let supported: Vec<String> =
+            styles.iter().map(|(style, _)| format!("'{}'", style.as_str())).collect();
+        format!("Preset '{name}' supports style {}.", supported.join(", "))
+    };
+    ("style", format!("style '{}' is not supported by preset '{name}'.", style.as_str()), hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_preset(preset: &str, style: Option<PresetStyle>) -> TransformConfig {
+        let rule =
+            TransformConfig { preset: Some(preset.to_string()), style, ..Default::default() };
+        expand(0, &rule).unwrap()
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_preset_antd_4() {
+        let config = expand_preset("antd@4", Some(PresetStyle::Css));
+        assert_eq!(config.source, "antd");
+        assert_eq!(
+            config.output,
+            strings(&["antd/es/{{ filename }}", "antd/es/{{ filename }}/style/css"])
+        );
+        assert_eq!(config.filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.specifier, Some(SpecifierType::Default));
+        assert_eq!(config.preset, None);
+        assert_eq!(config.style, None);
+
+        let config = expand_preset("antd@4", Some(PresetStyle::Less));
+        assert_eq!(config.output[1], "antd/es/{{ filename }}/style");
+    }
+
+    #[test]
+    fn test_preset_antd_5() {
+        let config = expand_preset("antd@5", None);
+        assert_eq!(config.source, "antd");
+        assert_eq!(config.output, strings(&["antd/es/{{ filename }}"]));
+        assert_eq!(config.filename, Some(FilenameCase::KebabCase));
+        assert_eq!(expand_preset("antd", None), config);
+    }
+
+    #[test]
+    fn test_preset_lodash() {
+        let config = expand_preset("lodash", None);
+        assert_eq!(config.source, "lodash");
+        assert_eq!(config.output, strings(&["lodash/{{ filename }}"]));
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+        assert_eq!(config.specifier, Some(SpecifierType::Default));
+
+        assert_eq!(expand_preset("lodash-es", None).output, strings(&["lodash-es/{{ filename }}"]));
+    }
+
+    #[test]
+    fn test_preset_mui() {
+        let config = expand_preset("@mui/material", None);
+        assert_eq!(config.source, "@mui/material");
+        assert_eq!(config.output, strings(&["@mui/material/{{ filename }}"]));
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+        assert!(config.exclude.unwrap().contains(&"createTheme".to_string()));
+
+        let config = expand_preset("@mui/icons-material", None);
+        assert_eq!(config.source, "@mui/icons-material");
+        assert_eq!(config.output, strings(&["@mui/icons-material/{{ filename }}"]));
+        assert_eq!(config.specifier, Some(SpecifierType::Default));
+    }
+
+    #[test]
+    fn test_preset_arco_design() {
+        let config = expand_preset("@arco-design/web-react", Some(PresetStyle::Css));
+        assert_eq!(config.source, "@arco-design/web-react");
+        assert_eq!(
+            config.output,
+            strings(&[
+                "@arco-design/web-react/es/{{ filename }}",
+                "@arco-design/web-react/es/{{ filename }}/style/css.js",
+            ])
+        );
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+    }
+
+    #[test]
+    fn test_preset_element_plus() {
+        let config = expand_preset("element-plus", Some(PresetStyle::Css));
+        assert_eq!(config.source, "element-plus");
+        assert_eq!(
+            config.output,
+            strings(&[
+                "element-plus/es/components/{{ filename }}/index.mjs",
+                "element-plus/es/components/{{ filename }}/style/css.mjs",
+            ])
+        );
+        assert_eq!(config.filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.specifier, Some(SpecifierType::Named));
+        assert_eq!(config.strip_prefix.as_deref(), Some("El"));
+    }
+
+    #[test]
+    fn test_preset_date_fns() {
+        let config = expand_preset("date-fns", None);
+        assert_eq!(config.source, "date-fns");
+        assert_eq!(config.output, strings(&["date-fns/{{ filename }}"]));
+        assert_eq!(config.specifier, Some(SpecifierType::Named));
+
+        assert_eq!(expand_preset("date-fns@2", None).specifier, Some(SpecifierType::Default));
+    }
+
+    #[test]
+    fn test_preset_ant_design_icons() {
+        let config = expand_preset("@ant-design/icons", None);
+        assert_eq!(config.source, "@ant-design/icons");
+        assert_eq!(config.output, strings(&["@ant-design/icons/es/icons/{{ filename }}"]));
+        assert_eq!(config.filename, Some(FilenameCase::Preserve));
+        assert!(config.exclude.unwrap().contains(&"createFromIconfontCN".to_string()));
+    }
+
+    #[test]
+    fn test_user_fields_override_preset() {
+        let rule = TransformConfig {
+            preset: Some("antd@4".to_string()),
+            style: Some(PresetStyle::Css),
+            output: strings(&["antd/lib/{{ filename }}"]),
+            specifier: Some(SpecifierType::Named),
+            include: Some(strings(&["Button"])),
+            on_unmatched: Some(crate::config::ReportLevel::Warn),
+            ..Default::default()
+        };
+
+        let config = expand(0, &rule).unwrap();
+        assert_eq!(config.source, "antd");
+        assert_eq!(config.output, strings(&["antd/lib/{{ filename }}"]));
+        assert_eq!(config.filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.specifier, Some(SpecifierType::Named));
+        assert_eq!(config.include, Some(strings(&["Button"])));
+        assert_eq!(config.on_unmatched, Some(crate::config::ReportLevel::Warn));
+    }
+
+    #[test]
+    fn test_user_filter_replaces_preset_filter() {
+        let rule = TransformConfig {
+            preset: Some("@mui/material".to_string()),
+            include: Some(strings(&["Button"])),
+            ..Default::default()
+        };
+
+        let config = expand(0, &rule).unwrap();
+        assert_eq!(config.include, Some(strings(&["Button"])));
+        assert_eq!(config.exclude, None);
+    }
+
+    #[test]
+    fn test_unknown_preset_suggests_name() {
+        let rule = TransformConfig { preset: Some("antd@6".to_string()), ..Default::default() };
+
+        let error = expand(2, &rule).unwrap_err();
+        assert!(error.to_string().contains("Config #2"));
+        assert!(error.to_string().contains("unknown preset 'antd@6'"));
+        assert_eq!(error.hint(), Some("did you mean one of 'antd@4', 'antd@5', 'antd'?"));
+    }
+
+    #[test]
+    fn test_preset_antd_5_css_style() {
+        // 请求中的示例配置
+        let (config, warnings) =
+            PluginConfig::load(r#"{ "config": [{ "preset": "antd@5", "style": "css" }] }"#)
+                .unwrap();
+        assert_eq!(config.config[0].source, "antd");
+        assert_eq!(config.config[0].output, strings(&["antd/es/{{ filename }}"]));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "config[0].style");
+        assert!(warnings[0].message.contains("adds no style imports"));
+
+        let (_, warnings) =
+            PluginConfig::load(r#"{ "config": [{ "preset": "antd@5" }] }"#).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_preset_style() {
+        let rule = TransformConfig {
+            preset: Some("antd@5".to_string()),
+            style: Some(PresetStyle::Less),
+            ..Default::default()
+        };
+        let error = expand(0, &rule).unwrap_err();
+        assert!(error.to_string().contains("style 'less' is not supported by preset 'antd@5'"));
+
+        let rule = TransformConfig { style: Some(PresetStyle::Css), ..Default::default() };
+        let error = expand(0, &rule).unwrap_err();
+        assert!(error.to_string().contains("'style' can only be used together with 'preset'"));
+    }
+}
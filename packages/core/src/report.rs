@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+use crate::config::ReportLevel;
+
+/// 一个模块的转换报告：哪些导入名称被改写、哪些被保留以及原因
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformReport {
+    /// 被改写的导入名称（按源码顺序）
+    pub rewritten: Vec<RewrittenSpecifier>,
+    /// 来自配置了规则的源模块、但保留在原导入中的说明符（按源码顺序）
+    pub leftovers: Vec<LeftoverSpecifier>,
+}
+
+impl TransformReport {
+    /// 模块中使用到的 (源模块, 导入名称)，用于汇总各文件使用了哪些组件
+    pub fn used_names(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.rewritten
+            .iter()
+            .map(|specifier| (specifier.source.as_str(), specifier.imported.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rewritten.is_empty() && self.leftovers.is_empty()
+    }
+}
+
+/// 被改写的命名导入
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewrittenSpecifier {
+    /// 原导入的源模块，如 `antd`
+    pub source: String,
+    /// 导入名称，如 `DatePicker`
+    pub imported: String,
+    /// 本地变量名（`import { DatePicker as Picker }` 中的 `Picker`）
+    pub local: String,
+    /// 处理它的规则在（展开后的）config 数组中的下标
+    pub rule: usize,
+    /// 规则为它生成的全部导入路径，第一个为主导入
+    /// 模块内重复的副作用导入只会输出一次，但在每个名称的报告中都会列出
+    pub paths: Vec<String>,
+}
+
+/// 保留在原导入中的说明符
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeftoverSpecifier {
+    pub source: String,
+    /// 导入名称；默认导入为 `default`，命名空间导入为 `*`
+    pub imported: String,
+    pub local: String,
+    pub reason: LeftoverReason,
+    /// 转换时上报这个说明符使用的诊断级别，keep 表示没有上报
+    pub level: ReportLevel,
+}
+
+/// 说明符没有被改写的原因
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum LeftoverReason {
+    /// `import { type Button }`
+    TypeOnly,
+    /// 默认导入或命名空间导入（整包导入），规则只处理命名导入
+    FullImport,
+    /// 匹配的规则声明了有效导出（exports/manifest），但名称不在其中
+    UnknownExport { rule: usize, suggestions: Vec<String> },
+    /// 没有规则匹配这个名称（include/exclude 排除了它）
+    Unmatched,
+}
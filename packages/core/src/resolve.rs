@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::compile::{CompiledConfig, CompiledRule};
+use crate::config::{SpecifierType, TransformConfig};
+use crate::suggest::closest_matches;
+use crate::version::CURRENT_DEFAULTS;
+
+/// 解析得到的一条导入
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResolvedImport {
+    /// 绑定导入名称的主导入，如 `import Button from "antd/es/button"`
+    Main { path: String, specifier: SpecifierType },
+    /// 副作用导入，如 `import "antd/es/button/style"`
+    SideEffect { path: String },
+}
+
+impl ResolvedImport {
+    pub fn path(&self) -> &str {
+        match self {
+            ResolvedImport::Main { path, .. } | ResolvedImport::SideEffect { path } => path,
+        }
+    }
+}
+
+/// 导入名称的解析结果
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resolution<'a> {
+    /// 由 `rule` 处理，`imports` 中主导入在前，依赖组件的副作用导入排在组件自身的副作用导入之前
+    Resolved { rule: &'a CompiledRule, imports: Vec<ResolvedImport> },
+    /// 匹配的规则声明了有效导出（exports/manifest），但名称不在其中
+    UnknownExport { rule: &'a CompiledRule, suggestions: Vec<&'a str> },
+    /// source 配置了规则，但没有规则匹配这个名称
+    Unmatched,
+    /// source 没有配置任何规则
+    Unconfigured,
+}
+
+impl CompiledConfig {
+    /// 解析从 `source` 导入的名称 `name` 应该改写成哪些导入
+    pub fn resolve(&self, source: &str, name: &str) -> Resolution<'_> {
+        match self.rules_for(source) {
+            [] => Resolution::Unconfigured,
+            rules => resolve_in(rules, name),
+        }
+    }
+}
+
+/// 在同一 source 的规则中解析导入名称，第一个匹配的规则生效
+pub fn resolve_in<'a>(rules: &'a [CompiledRule], name: &str) -> Resolution<'a> {
+    let Some(rule) = rules.iter().find(|rule| rule.matches(name)) else {
+        return Resolution::Unmatched;
+    };
+
+    if let Some(known_exports) = rule.known_exports.as_ref().filter(|known| !known.contains(name)) {
+        let suggestions = closest_matches(name, known_exports.iter().map(String::as_str), 3);
+        return Resolution::UnknownExport { rule, suggestions };
+    }
+
+    Resolution::Resolved { rule, imports: rule.imports_for(name) }
+}
+
+impl CompiledRule {
+    /// 按 output 模板生成导入名称对应的导入（不检查规则是否匹配这个名称）
+    pub fn imports_for(&self, name: &str) -> Vec<ResolvedImport> {
+        let filename = self.config.filename_for(name);
+        let mut templates = self.templates.iter();
+        let mut imports = Vec::with_capacity(self.templates.len());
+
+        // 第一个 output 生成主导入（根据 specifier 类型）
+        if let Some(main) = templates.next() {
+            imports.push(ResolvedImport::Main {
+                path: main.render(&filename),
+                specifier: self.config.specifier.unwrap_or(CURRENT_DEFAULTS.specifier),
+            });
+        }
+
+        // 依赖组件的副作用导入排在组件自身的副作用导入之前
+        for dependency in resolve_dependencies(name, &self.config) {
+            let dependency_filename = self.config.filename_for(&dependency);
+            imports.extend(templates.clone().map(|template| ResolvedImport::SideEffect {
+                path: template.render(&dependency_filename),
+            }));
+        }
+
+        // 后续 output 生成副作用导入
+        imports.extend(
+            templates
+                .map(|template| ResolvedImport::SideEffect { path: template.render(&filename) }),
+        );
+        imports
+    }
+}
+
+/// 按依赖顺序展开组件的传递依赖（不包含组件自身）
+///
+/// 采用后序深度优先遍历：被依赖的组件总是排在依赖它的组件之前，
+/// 重复出现的组件只保留第一次，循环依赖会被忽略。
+fn resolve_dependencies(name: &str, config: &TransformConfig) -> Vec<String> {
+    fn visit(
+        name: &str,
+        dependencies: &HashMap<String, Vec<String>>,
+        visited: &mut HashSet<String>,
+        ordered: &mut Vec<String>,
+    ) {
+        let Some(children) = dependencies.get(name) else {
+            return;
+        };
+        for child in children {
+            if visited.insert(child.clone()) {
+                visit(child, dependencies, visited, ordered);
+                ordered.push(child.clone());
+            }
+        }
+    }
+
+    let Some(dependencies) = &config.dependencies else {
+        return Vec::new();
+    };
+
+    let mut visited = HashSet::from([name.to_string()]);
+    let mut ordered = Vec::new();
+    visit(name, dependencies, &mut visited, &mut ordered);
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FilenameCase, PluginConfig};
+
+    fn compile(rules: Vec<TransformConfig>) -> CompiledConfig {
+        CompiledConfig::new(PluginConfig { config: rules, ..Default::default() }).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_outputs_and_specifier() {
+        let config = compile(vec![TransformConfig {
+            source: "antd".to_string(),
+            output: vec![
+                "antd/es/{{ filename }}".to_string(),
+                "antd/es/{{ filename }}/style".to_string(),
+            ],
+            filename: Some(FilenameCase::KebabCase),
+            include: Some(vec!["DatePicker".to_string()]),
+            ..Default::default()
+        }]);
+
+        let Resolution::Resolved { rule, imports } = config.resolve("antd", "DatePicker") else {
+            panic!("DatePicker should be resolved");
+        };
+        assert_eq!(rule.index, 0);
+        assert_eq!(
+            imports,
+            vec![
+                ResolvedImport::Main {
+                    path: "antd/es/date-picker".to_string(),
+                    specifier: SpecifierType::Default
+                },
+                ResolvedImport::SideEffect { path: "antd/es/date-picker/style".to_string() },
+            ]
+        );
+
+        assert_eq!(config.resolve("antd", "Button"), Resolution::Unmatched);
+        assert_eq!(config.resolve("react", "useState"), Resolution::Unconfigured);
+    }
+
+    #[test]
+    fn test_resolve_unknown_export() {
+        let config = compile(vec![TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}".to_string()],
+            exports: Some(vec!["Button".to_string(), "Table".to_string()]),
+            ..Default::default()
+        }]);
+
+        let Resolution::UnknownExport { suggestions, .. } = config.resolve("antd", "Buton") else {
+            panic!("Buton should be an unknown export");
+        };
+        assert_eq!(suggestions, vec!["Button"]);
+    }
+
+    #[test]
+    fn test_resolve_dependency_side_effects() {
+        let config = compile(vec![TransformConfig {
+            source: "antd".to_string(),
+            output: vec![
+                "antd/es/{{ filename }}".to_string(),
+                "antd/es/{{ filename }}/style".to_string(),
+            ],
+            dependencies: Some(HashMap::from([("Table".to_string(), vec!["Spin".to_string()])])),
+            ..Default::default()
+        }]);
+
+        let Resolution::Resolved { imports, .. } = config.resolve("antd", "Table") else {
+            panic!("Table should be resolved");
+        };
+        let paths: Vec<&str> = imports.iter().map(ResolvedImport::path).collect();
+        assert_eq!(paths, vec!["antd/es/table", "antd/es/spin/style", "antd/es/table/style"]);
+    }
+
+    #[test]
+    fn test_resolve_dependencies_in_dependency_order() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            dependencies: Some(HashMap::from([
+                (
+                    "Table".to_string(),
+                    vec!["Pagination".to_string(), "Checkbox".to_string(), "Spin".to_string()],
+                ),
+                ("Pagination".to_string(), vec!["Select".to_string()]),
+                ("Select".to_string(), vec!["Empty".to_string(), "Spin".to_string()]),
+            ])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_dependencies("Table", &config),
+            vec!["Empty", "Spin", "Select", "Pagination", "Checkbox"]
+        );
+        assert!(resolve_dependencies("Button", &config).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_dependencies_ignores_cycles() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}.js".to_string()],
+            dependencies: Some(HashMap::from([
+                ("Form".to_string(), vec!["Input".to_string()]),
+                ("Input".to_string(), vec!["Form".to_string()]),
+            ])),
+            ..Default::default()
+        };
+
+        assert_eq!(resolve_dependencies("Form", &config), vec!["Input"]);
+    }
+}
@@ -0,0 +1,146 @@
+//! 由配置类型生成的 JSON Schema（`packages/swc/schema.json`）和 TypeScript 类型声明（`packages/babel/src/types.ts`）
+//!
+//! 配置类型变化后运行 `UPDATE=1 cargo test` 重新生成。
+
+use std::path::Path;
+
+use crate::config::PluginConfig;
+
+/// 生成插件配置的 JSON Schema
+fn config_schema() -> String {
+    let schema = schemars::schema_for!(PluginConfig);
+    serde_json::to_string_pretty(&schema).unwrap() + "\n"
+}
+
+/// 比较生成结果与仓库中的文件，`UPDATE=1` 时直接覆盖
+fn assert_up_to_date(file: &str, generated: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(file);
+
+    if std::env::var("UPDATE").is_ok_and(|value| value == "1") {
+        std::fs::write(&path, generated).unwrap();
+        return;
+    }
+
+    let checked_in = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        checked_in == generated,
+        "{file} is out of date with the Rust config types, run `UPDATE=1 cargo test` to regenerate it"
+    );
+}
+
+#[test]
+fn test_json_schema_is_up_to_date() {
+    assert_up_to_date("../swc/schema.json", &config_schema());
+}
+
+#[test]
+fn test_json_schema_accepts_documented_config() {
+    let schema: serde_json::Value = serde_json::from_str(&config_schema()).unwrap();
+
+    // config 可以省略（例如只使用 babelPluginImport）
+    assert!(schema["required"].is_null());
+    assert!(schema["properties"]["babelPluginImport"].is_object());
+    assert_eq!(schema["additionalProperties"], serde_json::json!(false));
+    assert!(schema["$defs"]["TransformConfig"]["properties"]["onUnmatched"].is_object());
+}
+
+/// 生成 Babel 插件使用的 TypeScript 类型声明
+fn typescript_declarations() -> String {
+    use ts_rs::TS;
+
+    use crate::config::{FilenameCase, SpecifierType, TransformConfig};
+
+    fn declaration<T: TS>() -> String {
+        let cfg = ts_rs::Config::new();
+        let docs = T::docs().unwrap_or_default();
+        format!("{docs}export {}\n", format_object_type(&T::decl(&cfg)))
+    }
+
+    [
+        "// 此文件由 packages/core 中的 Rust 配置类型生成，请勿手动修改。\n\
+         // 修改配置类型后运行 `UPDATE=1 cargo test` 重新生成。\n"
+            .to_string(),
+        declaration::<FilenameCase>(),
+        declaration::<SpecifierType>(),
+        declaration::<TransformConfig>(),
+        declaration::<PluginConfig>(),
+    ]
+    .join("\n")
+}
+
+/// 将 ts-rs 生成的单行对象类型整理为每个字段一行、两个空格缩进的 interface
+///
+/// Babel 插件的使用者可能通过 `extends` 或声明合并扩展这些类型，因此对象类型输出为 interface 而不是 type 别名。
+fn format_object_type(decl: &str) -> String {
+    let Some((head, body)) = decl.split_once("{ \n") else {
+        return decl.to_string();
+    };
+    let head = match head.strip_prefix("type ").and_then(|head| head.strip_suffix(" = ")) {
+        Some(name) => format!("interface {name} "),
+        None => head.to_string(),
+    };
+
+    let mut lines = vec![format!("{head}{{")];
+    for line in body.lines() {
+        let line = line.trim();
+        if line.starts_with("/**") {
+            lines.push(format!("  {line}"));
+        } else if line.starts_with('*') {
+            lines.push(format!("   {line}"));
+        } else if let Some(field) = line.strip_suffix(", };") {
+            lines.push(format!("  {field};"));
+            lines.push("}".to_string());
+        } else {
+            lines.push(format!("  {};", line.trim_end_matches(',')));
+        }
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn test_typescript_declarations_are_up_to_date() {
+    assert_up_to_date("../babel/src/types.ts", &typescript_declarations());
+}
+
+/// 类型声明中某个 interface 的字段名称
+fn interface_fields<'a>(declarations: &'a str, name: &str) -> Vec<&'a str> {
+    let body = declarations.split(&format!("export interface {name} {{")).nth(1).unwrap();
+    body[..body.find("\n}").unwrap()]
+        .lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .map(|(field, _)| field.trim_end_matches('?'))
+        .collect()
+}
+
+#[test]
+fn test_babel_declarations_match_the_babel_plugin() {
+    let schema: serde_json::Value = serde_json::from_str(&config_schema()).unwrap();
+    let properties = schema["$defs"]["TransformConfig"]["properties"].as_object().unwrap();
+    let declarations = typescript_declarations();
+    let fields = interface_fields(&declarations, "TransformConfig");
+    assert_eq!(interface_fields(&declarations, "PluginConfig"), ["config"]);
+
+    let babel_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../babel/src");
+    let babel_sources = ["index.ts", "transform.ts"]
+        .map(|file| std::fs::read_to_string(babel_dir.join(file)).unwrap())
+        .concat();
+    let reads = |field: &str| {
+        babel_sources.match_indices(&format!(".{field}")).any(|(index, matched)| {
+            let next = babel_sources[index + matched.len()..].chars().next();
+            !next.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+    };
+
+    for property in properties.keys() {
+        // Babel 的配置必须仍是合法的 swc 插件配置，声明的字段都要由 Babel 插件读取；
+        // Babel 插件读取的字段也不能被 ts(skip) 排除在声明之外
+        assert_eq!(
+            fields.contains(&property.as_str()),
+            reads(property),
+            "'{property}' is declared for Babel only if the Babel plugin reads it"
+        );
+    }
+    for field in fields {
+        assert!(properties.contains_key(field), "{field}");
+    }
+}
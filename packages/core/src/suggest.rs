@@ -0,0 +1,83 @@
+/// 计算两个字符串之间的编辑距离（Levenshtein distance）
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+/// 从候选列表中找出与 name 最接近的若干个名称（用于 "did you mean" 提示）
+///
+/// 比较时忽略大小写，只保留编辑距离不超过名称长度三分之一（至少为 2）的候选，
+/// 结果按距离从近到远排序。
+pub fn closest_matches<'a, I>(name: &str, candidates: I, limit: usize) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let lowercase_name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(2);
+
+    let mut scored: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&lowercase_name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    scored.sort();
+    scored.dedup_by(|a, b| a.1 == b.1);
+
+    scored.into_iter().take(limit).map(|(_, candidate)| candidate).collect()
+}
+
+/// 将候选名称格式化为提示信息，没有候选时返回 None
+pub fn did_you_mean(suggestions: &[&str]) -> Option<String> {
+    match suggestions {
+        [] => None,
+        [only] => Some(format!("did you mean '{only}'?")),
+        _ => Some(format!(
+            "did you mean one of {}?",
+            suggestions.iter().map(|s| format!("'{s}'")).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("DatePicker", "DatePicker"), 0);
+        assert_eq!(edit_distance("DatPicker", "DatePicker"), 1);
+        assert_eq!(edit_distance("outputs", "output"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_closest_matches() {
+        let candidates = ["Button", "DatePicker", "TimePicker", "Table"];
+
+        assert_eq!(closest_matches("DatPicker", candidates, 3), vec!["DatePicker"]);
+        assert_eq!(closest_matches("table", candidates, 3), vec!["Table"]);
+        assert!(closest_matches("Carousel", candidates, 3).is_empty());
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(did_you_mean(&[]), None);
+        assert_eq!(did_you_mean(&["Table"]).unwrap(), "did you mean 'Table'?");
+        assert_eq!(
+            did_you_mean(&["Table", "Tabs"]).unwrap(),
+            "did you mean one of 'Table', 'Tabs'?"
+        );
+    }
+}
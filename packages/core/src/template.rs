@@ -0,0 +1,182 @@
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// 输出路径模板中支持的占位符
+const PLACEHOLDERS: &[&str] = &["filename"];
+
+/// 模板片段
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    /// 原样输出的文本
+    Literal(String),
+    /// `{{ filename }}`，替换为转换后的文件名
+    Filename,
+}
+
+/// 解析后的输出路径模板
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+/// 模板语法错误
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateError {
+    /// 出错位置（从 1 开始的字符列号）
+    pub column: usize,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+impl Template {
+    /// 解析模板，占位符两侧的空白会被忽略，如 `{{filename}}`、`{{  filename  }}`
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let column = |byte: usize| template[..byte].chars().count() + 1;
+        let mut segments = Vec::new();
+        let mut literal_start = 0;
+        let mut position = 0;
+
+        while position < template.len() {
+            let rest = &template[position..];
+            if rest.starts_with("}}") {
+                return Err(TemplateError {
+                    column: column(position),
+                    message: "unmatched '}}'".to_string(),
+                    hint: Some("Placeholders are written as '{{ filename }}'.".to_string()),
+                });
+            }
+            if !rest.starts_with("{{") {
+                position += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            }
+
+            let inner_start = position + 2;
+            let inner_end = template[inner_start..]
+                .find("}}")
+                .map(|offset| inner_start + offset)
+                .filter(|&end| !template[inner_start..end].contains("{{"));
+            let Some(inner_end) = inner_end else {
+                return Err(TemplateError {
+                    column: column(position),
+                    message: "unclosed '{{'".to_string(),
+                    hint: Some(
+                        "Close the placeholder with '}}', e.g. '{{ filename }}'.".to_string(),
+                    ),
+                });
+            };
+
+            let name = template[inner_start..inner_end].trim();
+            if !PLACEHOLDERS.contains(&name) {
+                let suggestions = closest_matches(name, PLACEHOLDERS.iter().copied(), 1);
+                return Err(TemplateError {
+                    column: column(position),
+                    message: format!("unknown placeholder '{{{{ {name} }}}}'"),
+                    hint: Some(did_you_mean(&suggestions).unwrap_or_else(|| {
+                        "The only supported placeholder is '{{ filename }}'.".to_string()
+                    })),
+                });
+            }
+
+            if literal_start < position {
+                segments.push(Segment::Literal(template[literal_start..position].to_string()));
+            }
+            segments.push(Segment::Filename);
+            position = inner_end + 2;
+            literal_start = position;
+        }
+
+        if literal_start < template.len() {
+            segments.push(Segment::Literal(template[literal_start..].to_string()));
+        }
+        Ok(Template { segments })
+    }
+
+    /// 用文件名替换占位符，生成导入路径
+    pub fn render(&self, filename: &str) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.as_str(),
+                Segment::Filename => filename,
+            })
+            .collect()
+    }
+
+    /// 模板是否包含 `{{ filename }}`
+    pub fn has_filename(&self) -> bool {
+        self.segments.contains(&Segment::Filename)
+    }
+
+    /// 检查模板文本中可能导致路径无效的写法，返回警告信息
+    pub fn lint(&self) -> Vec<String> {
+        let literal: String = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.as_str(),
+                Segment::Filename => "x",
+            })
+            .collect();
+
+        let mut warnings = Vec::new();
+        if literal.contains('\\') {
+            warnings.push("contains '\\'; module specifiers always use '/'".to_string());
+        }
+        if literal.chars().any(char::is_whitespace) {
+            warnings.push("contains whitespace outside of placeholders".to_string());
+        }
+        if literal.contains("//") {
+            warnings.push("contains '//'".to_string());
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render() {
+        let template = Template::parse("antd/es/{{ filename }}/style/{{filename}}.css").unwrap();
+        assert_eq!(template.render("date-picker"), "antd/es/date-picker/style/date-picker.css");
+        assert!(template.has_filename());
+
+        let template = Template::parse("antd/es/index.js").unwrap();
+        assert_eq!(template.render("button"), "antd/es/index.js");
+        assert!(!template.has_filename());
+    }
+
+    #[test]
+    fn test_unknown_placeholder() {
+        let error = Template::parse("antd/es/{{ fileName }}").unwrap_err();
+        assert_eq!(error.column, 9);
+        assert_eq!(error.message, "unknown placeholder '{{ fileName }}'");
+        assert_eq!(error.hint.as_deref(), Some("did you mean 'filename'?"));
+
+        let error = Template::parse("lodash/{{ member }}").unwrap_err();
+        assert_eq!(
+            error.hint.as_deref(),
+            Some("The only supported placeholder is '{{ filename }}'.")
+        );
+    }
+
+    #[test]
+    fn test_malformed_braces() {
+        let error = Template::parse("antd/es/{{ filename }").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (9, "unclosed '{{'"));
+
+        let error = Template::parse("antd/{{ {{ filename }}").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (6, "unclosed '{{'"));
+
+        let error = Template::parse("antd/es/filename }}").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (18, "unmatched '}}'"));
+    }
+
+    #[test]
+    fn test_lint() {
+        assert!(Template::parse("antd/es/{{ filename }}").unwrap().lint().is_empty());
+
+        let warnings = Template::parse("antd\\es//{{ filename }} .js").unwrap().lint();
+        assert_eq!(warnings.len(), 3);
+    }
+}
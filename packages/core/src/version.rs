@@ -0,0 +1,188 @@
+use crate::config::{FilenameCase, PluginConfig, ReportLevel, SpecifierType, TransformConfig};
+use crate::error::{ConfigError, ConfigWarning};
+
+/// 当前的配置版本
+pub const CURRENT_VERSION: u32 = 1;
+
+/// 某个配置版本中未配置字段的默认值
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Defaults {
+    pub filename: FilenameCase,
+    pub specifier: SpecifierType,
+    pub on_unknown_export: ReportLevel,
+    pub on_unmatched: ReportLevel,
+}
+
+/// 各配置版本的默认值（按版本号排列，新版本追加在末尾）
+const DEFAULTS: &[(u32, Defaults)] = &[(
+    1,
+    Defaults {
+        filename: FilenameCase::CamelCase,
+        specifier: SpecifierType::Default,
+        on_unknown_export: ReportLevel::Error,
+        on_unmatched: ReportLevel::Keep,
+    },
+)];
+
+/// 当前版本的默认值
+pub const CURRENT_DEFAULTS: Defaults = DEFAULTS[DEFAULTS.len() - 1].1;
+
+/// 获取指定配置版本的默认值
+pub fn defaults(version: u32) -> Option<Defaults> {
+    DEFAULTS.iter().find(|(v, _)| *v == version).map(|(_, defaults)| *defaults)
+}
+
+/// 将配置迁移到当前版本
+///
+/// 没有配置 version 的视为版本 1。规则中未配置的字段会按配置声明的版本显式填入默认值，
+/// 因此旧配置的转换结果不会因为默认值变化而改变；默认值与当前版本不同时返回警告。
+///
+/// 迁移只填入默认值，不会改写字段的名称或格式。
+pub fn migrate(
+    mut config: PluginConfig,
+) -> Result<(PluginConfig, Vec<ConfigWarning>), ConfigError> {
+    let version = config.version.unwrap_or(1);
+    let Some(from) = defaults(version) else {
+        let message = if version > CURRENT_VERSION {
+            format!("config version {version} is newer than this plugin supports.")
+        } else {
+            format!("config version {version} does not exist.")
+        };
+        return Err(ConfigError::unsupported(
+            "version",
+            message,
+            format!(
+                "Supported versions: 1 to {CURRENT_VERSION}. Upgrade the plugin or lower 'version'."
+            ),
+        ));
+    };
+
+    let warnings =
+        migrate_rules(&mut config.config, (version, &from), (CURRENT_VERSION, &CURRENT_DEFAULTS));
+    config.version = Some(CURRENT_VERSION);
+    Ok((config, warnings))
+}
+
+/// 为规则填入 from 版本的默认值，与 to 版本默认值不同的字段产生警告
+///
+/// from 和 to 均为（版本号，该版本的默认值）。
+pub fn migrate_rules(
+    rules: &mut [TransformConfig],
+    (version, from): (u32, &Defaults),
+    (to_version, to): (u32, &Defaults),
+) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    for (index, rule) in rules.iter_mut().enumerate() {
+        let mut warn = |field: &str, old: &str, new: &str| {
+            warnings.push(ConfigWarning {
+                path: format!("config[{index}].{field}"),
+                message: format!(
+                    "Config #{index} (source: '{}') relies on the version {version} default \
+                    '{field}: {old}', which is '{new}' in version {to_version}; \
+                    the rule keeps '{old}'.",
+                    rule.source,
+                ),
+                hint: Some(format!(
+                    "Set '{field}' explicitly and 'version' to {to_version} to silence this warning."
+                )),
+            });
+        };
+
+        if rule.filename.is_none() && from.filename != to.filename {
+            warn("filename", &config_name(&from.filename), &config_name(&to.filename));
+        }
+        if rule.specifier.is_none() && from.specifier != to.specifier {
+            warn("specifier", &config_name(&from.specifier), &config_name(&to.specifier));
+        }
+        if rule.on_unknown_export.is_none() && from.on_unknown_export != to.on_unknown_export {
+            let (old, new) =
+                (config_name(&from.on_unknown_export), config_name(&to.on_unknown_export));
+            warn("onUnknownExport", &old, &new);
+        }
+        if rule.on_unmatched.is_none() && from.on_unmatched != to.on_unmatched {
+            warn("onUnmatched", &config_name(&from.on_unmatched), &config_name(&to.on_unmatched));
+        }
+
+        rule.filename.get_or_insert(from.filename);
+        rule.specifier.get_or_insert(from.specifier);
+        rule.on_unknown_export.get_or_insert(from.on_unknown_export);
+        rule.on_unmatched.get_or_insert(from.on_unmatched);
+    }
+
+    warnings
+}
+
+/// 配置中使用的名称（如 camelCase）
+fn config_name<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> TransformConfig {
+        TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_current_defaults() {
+        assert_eq!(defaults(CURRENT_VERSION), Some(CURRENT_DEFAULTS));
+        assert_eq!(CURRENT_DEFAULTS.filename, FilenameCase::CamelCase);
+    }
+
+    #[test]
+    fn test_migrate_fills_implicit_defaults() {
+        let config = PluginConfig {
+            config: vec![TransformConfig { filename: Some(FilenameCase::KebabCase), ..rule() }],
+            ..Default::default()
+        };
+
+        let (config, warnings) = migrate(config).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(config.version, Some(CURRENT_VERSION));
+        assert_eq!(config.config[0].filename, Some(FilenameCase::KebabCase));
+        assert_eq!(config.config[0].specifier, Some(SpecifierType::Default));
+        assert_eq!(config.config[0].on_unknown_export, Some(ReportLevel::Error));
+        assert_eq!(config.config[0].on_unmatched, Some(ReportLevel::Keep));
+    }
+
+    #[test]
+    fn test_migrate_rejects_unknown_version() {
+        let config = PluginConfig { version: Some(CURRENT_VERSION + 1), ..Default::default() };
+        let error = migrate(config).unwrap_err();
+        assert!(error.to_string().contains("is newer than this plugin supports"));
+
+        let config = PluginConfig { version: Some(0), ..Default::default() };
+        assert!(
+            migrate(config).unwrap_err().to_string().contains("config version 0 does not exist")
+        );
+    }
+
+    #[test]
+    fn test_migrate_warns_when_implicit_default_changed() {
+        let to = Defaults { filename: FilenameCase::KebabCase, ..CURRENT_DEFAULTS };
+        let mut rules =
+            vec![rule(), TransformConfig { filename: Some(FilenameCase::PascalCase), ..rule() }];
+
+        let warnings = migrate_rules(&mut rules, (1, &CURRENT_DEFAULTS), (2, &to));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "config[0].filename");
+        assert!(warnings[0].message.contains("version 1 default 'filename: camelCase'"));
+        assert!(warnings[0].message.contains("'kebabCase' in version 2"));
+        assert_eq!(
+            warnings[0].hint.as_deref(),
+            Some("Set 'filename' explicitly and 'version' to 2 to silence this warning.")
+        );
+        assert_eq!(rules[0].filename, Some(FilenameCase::CamelCase));
+        assert_eq!(rules[1].filename, Some(FilenameCase::PascalCase));
+    }
+}
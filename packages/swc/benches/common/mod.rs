@@ -0,0 +1,114 @@
+//! 基准测试共用的合成配置和模块
+
+// 每个基准测试只用到其中一部分
+#![allow(dead_code)]
+
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::ast::{
+    Ident, ImportDecl, ImportNamedSpecifier, ImportSpecifier, ModuleDecl, ModuleItem, Str,
+};
+use swc_plugin_transform_import_declaration::transform::{
+    FilenameCase, PluginConfig, TransformConfig,
+};
+
+/// 组件库规则数量
+pub const LIBRARIES: usize = 20;
+/// 图标库导出的名称数量
+pub const ICONS: usize = 5000;
+
+/// 模拟图标库的导出名称
+pub fn icon_names(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("Icon{i}Outlined")).collect()
+}
+
+/// 模拟组件库的导出名称
+pub fn component_names(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("DataGrid{i}Panel")).collect()
+}
+
+pub fn import(source: &str, names: &[String]) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: names
+            .iter()
+            .map(|name| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: Ident::new_no_ctxt(name.as_str().into(), DUMMY_SP),
+                    imported: None,
+                    is_type_only: false,
+                })
+            })
+            .collect(),
+        src: Box::new(Str { span: DUMMY_SP, value: source.into(), raw: None }),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }))
+}
+
+/// 多个组件库规则（带样式导入）加上一个带有数千个名称 include 列表的图标库规则
+pub fn config() -> PluginConfig {
+    let mut rules: Vec<TransformConfig> = (0..LIBRARIES)
+        .map(|i| TransformConfig {
+            source: format!("library-{i}"),
+            filename: Some(FilenameCase::KebabCase),
+            output: vec![
+                format!("library-{i}/es/{{{{ filename }}}}"),
+                format!("library-{i}/es/{{{{ filename }}}}/style/css"),
+            ],
+            ..Default::default()
+        })
+        .collect();
+    rules.push(TransformConfig {
+        source: "icons".to_string(),
+        filename: Some(FilenameCase::KebabCase),
+        output: vec!["icons/es/icons/{{ filename }}".to_string()],
+        include: Some(icon_names(ICONS)),
+        ..Default::default()
+    });
+    PluginConfig { config: rules, ..Default::default() }
+}
+
+/// 原始 JSON 配置（插件实际收到的形式）
+pub fn raw_config() -> String {
+    serde_json::json!({ "config": config().config }).to_string()
+}
+
+/// 200 个导入语句：一半来自配置中的源模块，一半不匹配任何规则
+pub fn mixed_module() -> Vec<ModuleItem> {
+    let icons = icon_names(ICONS);
+    (0..200)
+        .map(|i| {
+            let names: Vec<String> = icons.iter().skip(i * 20).take(10).cloned().collect();
+            match i % 4 {
+                0 => import("icons", &names),
+                1 => import(&format!("library-{}", i % LIBRARIES), &names[..5]),
+                _ => import(&format!("./local-{i}"), &names[..5]),
+            }
+        })
+        .collect()
+}
+
+/// 每个组件库各有若干个导入语句，共 `imports` 个，每个导入 `specifiers` 个名称
+pub fn library_module(imports: usize, specifiers: usize) -> Vec<ModuleItem> {
+    let names = component_names(specifiers * imports);
+    (0..imports)
+        .map(|i| {
+            import(
+                &format!("library-{}", i % LIBRARIES),
+                &names[i * specifiers..(i + 1) * specifiers],
+            )
+        })
+        .collect()
+}
+
+/// 一个从图标库导入 `count` 个图标的导入语句（图标选择页、图标文档等）
+pub fn icon_module(count: usize) -> Vec<ModuleItem> {
+    vec![import("icons", &icon_names(count))]
+}
+
+/// 打包后的第三方代码：大量导入，没有任何一个命中配置
+pub fn unmatched_module(imports: usize) -> Vec<ModuleItem> {
+    (0..imports).map(|i| import(&format!("./vendor/chunk-{i}"), &[])).collect()
+}
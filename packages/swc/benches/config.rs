@@ -0,0 +1,28 @@
+//! 配置加载和转换器构建的耗时（每个文件都会经历一次）
+
+mod common;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use swc_plugin_transform_import_declaration::transform::{ImportTransformer, PluginConfig};
+use transform_import_declaration_core::cache;
+
+fn bench_config(c: &mut Criterion) {
+    let raw = common::raw_config();
+    let config = common::config();
+
+    let mut group = c.benchmark_group("config");
+    group.bench_function("load", |b| b.iter(|| PluginConfig::load(&raw).unwrap()));
+    // 第一次之后命中缓存
+    group.bench_function("load (cached)", |b| b.iter(|| cache::load(&raw).0.unwrap()));
+    group.bench_function("build transformer", |b| {
+        b.iter_batched(
+            || config.clone(),
+            |config| ImportTransformer::new(config).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_config);
+criterion_main!(benches);
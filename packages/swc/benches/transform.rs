@@ -0,0 +1,37 @@
+//! `ImportTransformer` 在合成模块上的转换耗时
+//!
+//! 比较两个提交：`pnpm bench -- --save-baseline main` 后切换分支运行
+//! `pnpm bench -- --baseline main`。
+
+mod common;
+
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use swc_core::ecma::ast::ModuleItem;
+use swc_core::ecma::visit::VisitMut;
+use swc_plugin_transform_import_declaration::transform::ImportTransformer;
+
+fn bench_module(c: &mut Criterion, name: &str, items: Vec<ModuleItem>) {
+    let mut transformer = ImportTransformer::new(common::config()).unwrap();
+
+    let mut group = c.benchmark_group("transform");
+    group.throughput(Throughput::Elements(items.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter_batched_ref(
+            || items.clone(),
+            |items| transformer.visit_mut_module_items(items),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_transform(c: &mut Criterion) {
+    bench_module(c, "200 mixed imports", common::mixed_module());
+    bench_module(c, "500 imports x 5 specifiers", common::library_module(500, 5));
+    bench_module(c, "20 imports x 200 specifiers", common::library_module(20, 200));
+    bench_module(c, "1000 icons / 5000-name include", common::icon_module(1000));
+    bench_module(c, "5000 unmatched imports", common::unmatched_module(5000));
+}
+
+criterion_group!(benches, bench_transform);
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+use swc_core::common::Span;
+use swc_core::common::errors::HANDLER;
+use transform_import_declaration_core::config::ReportLevel;
+#[cfg(feature = "plugin")]
+use transform_import_declaration_core::error::{ConfigError, ConfigWarning};
+
+/// 通过 swc 的 HANDLER 上报诊断信息
+///
+/// 宿主（swc、rspack 等）会将诊断信息与源码位置一起展示。
+/// `ReportLevel::Keep` 不产生任何输出；没有设置 HANDLER 时（例如在宿主之外直接调用）同样忽略。
+pub fn report(level: ReportLevel, span: Span, message: &str, help: Option<&str>) {
+    if level == ReportLevel::Keep || !HANDLER.is_set() {
+        return;
+    }
+
+    HANDLER.with(|handler| {
+        let mut diagnostic = match level {
+            ReportLevel::Error => handler.struct_span_err(span, message),
+            ReportLevel::Warn => handler.struct_span_warn(span, message),
+            ReportLevel::Keep => unreachable!(),
+        };
+        if let Some(help) = help {
+            diagnostic.help(help);
+        }
+        diagnostic.emit();
+    });
+}
+
+/// 通过 swc 的 HANDLER 上报配置错误
+#[cfg(feature = "plugin")]
+pub fn report_config_error(error: &ConfigError) {
+    if !HANDLER.is_set() {
+        return;
+    }
+
+    HANDLER.with(|handler| {
+        let mut diagnostic = handler.struct_err(&error.to_string());
+        if let Some(hint) = error.hint() {
+            diagnostic.help(hint);
+        }
+        diagnostic.emit();
+    });
+}
+
+/// 通过 swc 的 HANDLER 上报配置警告
+#[cfg(feature = "plugin")]
+pub fn report_config_warning(warning: &ConfigWarning) {
+    if !HANDLER.is_set() {
+        return;
+    }
+
+    HANDLER.with(|handler| {
+        let mut diagnostic = handler.struct_warn(&warning.to_string());
+        if let Some(hint) = &warning.hint {
+            diagnostic.help(hint);
+        }
+        diagnostic.emit();
+    });
+}
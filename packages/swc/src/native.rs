@@ -0,0 +1,632 @@
+//! 作为普通 Rust 库在原生 swc 管道中使用导入转换（`native` feature）
+//!
+//! ```toml
+//! swc_plugin_transform_import_declaration = { version = "0.1", default-features = false, features = ["native"] }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use swc_core::common::comments::{Comments, SingleThreadedComments};
+use swc_core::common::errors::{DiagnosticBuilder, Emitter, HANDLER, Handler, Level};
+use swc_core::common::sync::Lrc;
+use swc_core::common::{
+    BytePos, DUMMY_SP, FileName, GLOBALS, Globals, SourceFile, SourceMap, Span, Spanned,
+};
+use swc_core::ecma::ast::{EsVersion, ImportDecl, Module, ModuleDecl, ModuleItem, Pass};
+use swc_core::ecma::codegen::Emitter as CodeEmitter;
+use swc_core::ecma::codegen::text_writer::JsWriter;
+use swc_core::ecma::parser::{Syntax, TsSyntax, parse_file_as_module};
+use swc_core::ecma::visit::{VisitMut, VisitMutWith, visit_mut_pass};
+pub use transform_import_declaration_core::builder::{PluginConfigBuilder, TransformConfigBuilder};
+use transform_import_declaration_core::error::ConfigError;
+pub use transform_import_declaration_core::report::{
+    LeftoverReason, LeftoverSpecifier, RewrittenSpecifier, TransformReport,
+};
+
+use crate::transform::{ImportTransformer, PluginConfig, ReportLevel};
+
+/// 创建导入转换 Pass，用于在原生 swc 管道中运行
+///
+/// 配置与加载 JSON 配置时一样先展开（extends、preset、babelPluginImport、modularizeImports）并验证，
+/// 配置无效时返回错误。
+///
+/// 需要按文件名匹配 `allowFullImport` 或需要转换报告时，直接使用 [`ImportTransformer`]：
+/// `ImportTransformer::new(config)?.with_filename(filename).with_report()`，
+/// 用 `program.visit_mut_with(&mut transformer)` 转换后调用 `transformer.take_report()`。
+pub fn import_declaration_pass(config: PluginConfig) -> Result<impl Pass, ConfigError> {
+    let (config, _) = config.prepare()?;
+    Ok(visit_mut_pass(ImportTransformer::new(config)?))
+}
+
+/// 转换失败的原因
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransformError {
+    /// 配置无效
+    Config(ConfigError),
+    /// 源码无法解析（行列号从 1 开始）
+    Parse { line: usize, column: usize, message: String },
+    /// 转换时上报了 error 级别的诊断信息（如未知的导出名称、被禁止的整包导入）
+    Diagnostics(Vec<String>),
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransformError::Config(error) => error.fmt(f),
+            TransformError::Parse { line, column, message } => {
+                write!(f, "failed to parse source at line {line}, column {column}: {message}")
+            }
+            TransformError::Diagnostics(messages) => f.write_str(&messages.join("\n")),
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+impl From<ConfigError> for TransformError {
+    fn from(error: ConfigError) -> Self {
+        TransformError::Config(error)
+    }
+}
+
+/// 转换时上报的一条诊断信息
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// 诊断级别（warn 或 error）
+    pub level: ReportLevel,
+    /// 在原源码中的位置
+    pub location: Option<Location>,
+    pub message: String,
+    /// 修复建议
+    pub help: Vec<String>,
+}
+
+/// 收集到的诊断信息：(级别, 位置, 消息, 帮助信息)
+type Collected = (ReportLevel, Option<Span>, String, Vec<String>);
+
+/// 收集 warn 和 error 级别诊断信息的 Emitter
+#[derive(Clone, Default)]
+struct DiagnosticCollector(Arc<Mutex<Vec<Collected>>>);
+
+impl DiagnosticCollector {
+    /// 将收集到的诊断信息的位置转换为行列号
+    fn take(&self, cm: &SourceMap) -> Vec<Diagnostic> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+            .into_iter()
+            .map(|(level, span, message, help)| Diagnostic {
+                level,
+                location: span.map(|span| location(cm, span.lo)),
+                message,
+                help,
+            })
+            .collect()
+    }
+}
+
+impl Emitter for DiagnosticCollector {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        let level = match db.level {
+            Level::Error => ReportLevel::Error,
+            Level::Warning => ReportLevel::Warn,
+            _ => return,
+        };
+        let help = db.children.iter().map(|child| child.message()).collect();
+        self.0.lock().unwrap().push((level, db.span.primary_span(), db.message(), help));
+    }
+}
+
+/// 转换一段 JavaScript/TypeScript（含 JSX）源码，返回转换后的代码
+///
+/// 源码按 TSX 语法解析，注释会被保留；输出由 swc codegen 生成，格式与原文不完全相同。
+///
+/// ```
+/// use swc_plugin_transform_import_declaration::native::transform_source;
+/// use swc_plugin_transform_import_declaration::transform::{PluginConfig, TransformConfig};
+///
+/// let config = PluginConfig::builder()
+///     .rule(TransformConfig::builder("antd").preset("antd@5"))
+///     .build()
+///     .unwrap();
+/// let code = transform_source(r#"import { DatePicker } from "antd";"#, config).unwrap();
+/// assert_eq!(code, "import DatePicker from \"antd/es/date-picker\";\n");
+/// ```
+pub fn transform_source(code: &str, config: PluginConfig) -> Result<String, TransformError> {
+    transform_source_with_report(code, config).map(|(code, _)| code)
+}
+
+/// 同 [`transform_source`]，同时返回转换报告（改写了哪些导入名称、保留了哪些以及原因）
+pub fn transform_source_with_report(
+    code: &str,
+    config: PluginConfig,
+) -> Result<(String, TransformReport), TransformError> {
+    let (config, _) = config.prepare()?;
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), code.to_string());
+    let comments = SingleThreadedComments::default();
+    let collector = DiagnosticCollector::default();
+    let handler = Handler::with_emitter(true, false, Box::new(collector.clone()));
+
+    let (module, report) = GLOBALS.set(&Globals::new(), || {
+        let syntax =
+            Syntax::Typescript(TsSyntax { tsx: true, decorators: true, ..Default::default() });
+        let mut module = parse(&cm, &fm, syntax, Some(&comments))?;
+
+        let mut transformer = ImportTransformer::new(config)?.with_report();
+        HANDLER.set(&handler, || module.visit_mut_with(&mut transformer));
+        Ok::<_, TransformError>((module, transformer.take_report().unwrap_or_default()))
+    })?;
+
+    let errors: Vec<String> = collector
+        .take(&cm)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.level == ReportLevel::Error)
+        .map(|diagnostic| {
+            let mut message = diagnostic.message;
+            for help in diagnostic.help {
+                message.push_str(&format!("\n  = help: {help}"));
+            }
+            message
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(TransformError::Diagnostics(errors));
+    }
+
+    let mut output = Vec::new();
+    {
+        let mut emitter = CodeEmitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: Some(&comments),
+            wr: JsWriter::new(cm.clone(), "\n", &mut output, None),
+        };
+        emitter.emit_module(&module).expect("writing to a Vec<u8> cannot fail");
+    }
+    Ok((String::from_utf8(output).expect("codegen generated non-utf8 output"), report))
+}
+
+/// 源码中的位置（行列号从 1 开始）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// [`rewrite_source`] 的结果
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rewrite {
+    /// 重写后的源码，没有导入被重写时为 None
+    pub code: Option<String>,
+    /// 转换报告
+    pub report: TransformReport,
+    /// 导入名称在原源码中的位置，按本地名称索引
+    pub locations: HashMap<String, Location>,
+    /// 转换时上报的诊断信息（如未知的导出名称、被禁止的整包导入）
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Rewrite {
+    /// 是否有 error 级别的诊断信息，此时不应写入重写结果
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|diagnostic| diagnostic.level == ReportLevel::Error)
+    }
+}
+
+/// 转换源码并只重写发生变化的导入声明，其余内容（格式、注释、空行）保持原样
+///
+/// 适合作为 codemod 直接修改源码：新生成的导入沿用原导入的引号风格和换行符。
+/// 转换时的诊断信息收集在 [`Rewrite::diagnostics`] 中，不会导致失败，由调用方决定如何处理。
+pub fn rewrite_source(
+    code: &str,
+    syntax: Syntax,
+    transformer: ImportTransformer,
+) -> Result<Rewrite, TransformError> {
+    // swc 会去掉 BOM，偏移量相对于去掉 BOM 后的源码
+    let (bom, code) = match code.strip_prefix('\u{feff}') {
+        Some(rest) => ("\u{feff}", rest),
+        None => ("", code),
+    };
+    let new_line = if code.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), code.to_string());
+    let mut transformer = transformer.with_report();
+    let collector = DiagnosticCollector::default();
+    let handler = Handler::with_emitter(true, false, Box::new(collector.clone()));
+
+    GLOBALS.set(&Globals::new(), || {
+        let comments = SingleThreadedComments::default();
+        let module = parse(&cm, &fm, syntax, Some(&comments))?;
+        let comment_spans = comment_spans(comments);
+        let offset = |pos: BytePos| (pos.0 - fm.start_pos.0) as usize;
+
+        let mut output = String::with_capacity(code.len());
+        output.push_str(bom);
+        let mut last = 0;
+        let rewrites = HANDLER.set(&handler, || transformer.rewrite_imports(&module.body));
+        for (index, mut items) in rewrites {
+            let original = &module.body[index];
+            if items.len() == 1 && items[0] == *original {
+                continue;
+            }
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = original {
+                let quote = import_decl.src.raw.as_ref().and_then(|raw| raw.chars().next());
+                if let Some(quote) = quote {
+                    items.visit_mut_with(&mut QuoteStyle(quote));
+                }
+            }
+
+            let span = original.span();
+            output.push_str(&code[last..offset(span.lo)]);
+            // 导入声明内部的注释（如 `import { Button, // 说明`）移到生成的导入之前，避免被删除
+            for comment in comment_spans.iter().filter(|c| c.lo > span.lo && c.hi < span.hi) {
+                output.push_str(&code[offset(comment.lo)..offset(comment.hi)]);
+                output.push_str(new_line);
+            }
+            output.push_str(emit_items(&cm, items, new_line).trim_end());
+            last = offset(span.hi);
+        }
+        // last 为 0 说明没有任何导入被重写
+        let code = (last > 0).then(|| output + &code[last..]);
+
+        let mut locations = HashMap::new();
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+                continue;
+            };
+            for specifier in &import_decl.specifiers {
+                locations
+                    .insert(specifier.local().sym.to_string(), location(&cm, specifier.span().lo));
+            }
+        }
+
+        Ok(Rewrite {
+            code,
+            report: transformer.take_report().unwrap_or_default(),
+            locations,
+            diagnostics: collector.take(&cm),
+        })
+    })
+}
+
+/// 源码中某个位置的行列号
+fn location(cm: &SourceMap, pos: BytePos) -> Location {
+    let position = cm.lookup_char_pos(pos);
+    Location { line: position.line, column: position.col_display + 1 }
+}
+
+/// 按位置排序的所有注释的范围
+fn comment_spans(comments: SingleThreadedComments) -> Vec<Span> {
+    let (leading, trailing) = comments.take_all();
+    let (leading, trailing) = (leading.borrow(), trailing.borrow());
+    let mut spans: Vec<Span> =
+        leading.values().chain(trailing.values()).flatten().map(|comment| comment.span).collect();
+    spans.sort_by_key(|span| span.lo);
+    spans.dedup();
+    spans
+}
+
+/// 解析源码，将解析错误（包括可恢复的错误）转换为带行列号的 `TransformError::Parse`
+fn parse(
+    cm: &SourceMap,
+    fm: &SourceFile,
+    syntax: Syntax,
+    comments: Option<&dyn Comments>,
+) -> Result<Module, TransformError> {
+    let mut recovered = Vec::new();
+    let result = parse_file_as_module(fm, syntax, EsVersion::latest(), comments, &mut recovered);
+    let error = match result {
+        Ok(module) if recovered.is_empty() => return Ok(module),
+        Ok(_) => recovered.swap_remove(0),
+        Err(error) => error,
+    };
+    let Location { line, column } = location(cm, error.span().lo);
+    Err(TransformError::Parse { line, column, message: error.into_kind().msg().into_owned() })
+}
+
+/// 生成语句的代码（不含注释）
+fn emit_items(cm: &Lrc<SourceMap>, items: Vec<ModuleItem>, new_line: &str) -> String {
+    let module = Module { span: DUMMY_SP, body: items, shebang: None };
+    let mut output = Vec::new();
+    {
+        let mut emitter = CodeEmitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm.clone(), new_line, &mut output, None),
+        };
+        emitter.emit_module(&module).expect("writing to a Vec<u8> cannot fail");
+    }
+    String::from_utf8(output).expect("codegen generated non-utf8 output")
+}
+
+/// 让新生成的导入路径使用指定的引号
+struct QuoteStyle(char);
+
+impl VisitMut for QuoteStyle {
+    fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+        let src = &mut import_decl.src;
+        if src.raw.is_none() {
+            if let Some(value) = src.value.as_str() {
+                src.raw = Some(format!("{0}{value}{0}", self.0).into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::ecma::parser::EsSyntax;
+
+    use crate::transform::{FilenameCase, ReportLevel, TransformConfig};
+
+    fn config() -> PluginConfig {
+        PluginConfig::builder()
+            .rule(
+                TransformConfig::builder("antd")
+                    .filename(FilenameCase::KebabCase)
+                    .output("antd/es/{{ filename }}")
+                    .output("antd/es/{{ filename }}/style")
+                    .exports(["Button", "DatePicker"])
+                    .on_unknown_export(ReportLevel::Error),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transform_source() {
+        let code = "// components\nimport { Button } from \"antd\";\nconst el = <Button />;\n";
+        assert_eq!(
+            transform_source(code, config()).unwrap(),
+            "// components\nimport Button from \"antd/es/button\";\nimport \"antd/es/button/style\";\nconst el = <Button/>;\n"
+        );
+    }
+
+    #[test]
+    fn test_transform_source_errors() {
+        let error = transform_source("import { Buton } from \"antd\";", config()).unwrap_err();
+        assert_eq!(
+            error,
+            TransformError::Diagnostics(vec![
+                "'Buton' is not a known export of 'antd'\n  = help: did you mean 'Button'?"
+                    .to_string()
+            ])
+        );
+
+        let error = transform_source("import { from \"antd\";", config()).unwrap_err();
+        assert!(matches!(error, TransformError::Parse { line: 1, .. }));
+
+        let invalid =
+            PluginConfig { config: vec![TransformConfig::default()], ..Default::default() };
+        assert!(matches!(transform_source("", invalid), Err(TransformError::Config(_))));
+    }
+
+    #[test]
+    fn test_transform_source_with_report() {
+        let config = PluginConfig::builder()
+            .rule(
+                TransformConfig::builder("antd")
+                    .filename(FilenameCase::KebabCase)
+                    .output("antd/es/{{ filename }}")
+                    .output("antd/es/{{ filename }}/style")
+                    .exclude(["message"])
+                    .exports(["Button", "DatePicker", "message"])
+                    .on_unknown_export(ReportLevel::Warn),
+            )
+            .build()
+            .unwrap();
+        let code = r#"import antd, { DatePicker as Picker, Buton, message, type ButtonProps } from "antd";"#;
+        let (_, report) = transform_source_with_report(code, config).unwrap();
+
+        assert_eq!(
+            report.rewritten,
+            vec![RewrittenSpecifier {
+                source: "antd".to_string(),
+                imported: "DatePicker".to_string(),
+                local: "Picker".to_string(),
+                rule: 0,
+                paths: vec![
+                    "antd/es/date-picker".to_string(),
+                    "antd/es/date-picker/style".to_string()
+                ],
+            }]
+        );
+        let leftovers: Vec<(&str, &LeftoverReason)> = report
+            .leftovers
+            .iter()
+            .map(|leftover| (leftover.imported.as_str(), &leftover.reason))
+            .collect();
+        assert_eq!(
+            leftovers,
+            vec![
+                ("default", &LeftoverReason::FullImport),
+                (
+                    "Buton",
+                    &LeftoverReason::UnknownExport {
+                        rule: 0,
+                        suggestions: vec!["Button".to_string()]
+                    }
+                ),
+                ("message", &LeftoverReason::Unmatched),
+                ("ButtonProps", &LeftoverReason::TypeOnly),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_source_keeps_formatting() {
+        let code = "\
+// components
+import React from 'react';
+import {Button,   message} from 'antd';  // trailing
+
+const  el = <Button  />;
+";
+        let syntax = Syntax::Es(EsSyntax { jsx: true, ..Default::default() });
+        let rewrite =
+            rewrite_source(code, syntax, ImportTransformer::new(config()).unwrap()).unwrap();
+        assert_eq!(
+            rewrite.code.unwrap(),
+            "\
+// components
+import React from 'react';
+import Button from 'antd/es/button';
+import 'antd/es/button/style';
+import { message } from 'antd';  // trailing
+
+const  el = <Button  />;
+"
+        );
+
+        assert_eq!(rewrite.report.rewritten[0].imported, "Button");
+        assert_eq!(rewrite.report.leftovers[0].imported, "message");
+        assert_eq!(rewrite.locations["Button"], Location { line: 3, column: 9 });
+        assert_eq!(rewrite.locations["message"], Location { line: 3, column: 19 });
+        assert_eq!(rewrite.locations["React"], Location { line: 2, column: 8 });
+    }
+
+    #[test]
+    fn test_rewrite_source_unchanged() {
+        let transformer = || ImportTransformer::new(config()).unwrap();
+        let syntax = Syntax::Typescript(TsSyntax::default());
+
+        // 没有 exports 时类型导入也不能被当作组件改写
+        let config = PluginConfig::builder()
+            .rule(
+                TransformConfig::builder("antd")
+                    .filename(FilenameCase::KebabCase)
+                    .output("antd/es/{{ filename }}")
+                    .output("antd/es/{{ filename }}/style"),
+            )
+            .build()
+            .unwrap();
+        let code = "\u{feff}import type { ButtonProps } from \"antd\";\r\nimport \"antd\";\r\nimport { a } from \"b\";\r\n";
+        let rewrite =
+            rewrite_source(code, syntax, ImportTransformer::new(config).unwrap()).unwrap();
+        assert_eq!(rewrite.code, None);
+        assert!(rewrite.report.rewritten.is_empty());
+        assert_eq!(rewrite.report.leftovers[0].imported, "ButtonProps");
+        assert_eq!(rewrite.report.leftovers[0].reason, LeftoverReason::TypeOnly);
+
+        let code = "\u{feff}import { Button } from \"antd\";\r\nlet a: number;\r\n";
+        assert_eq!(
+            rewrite_source(code, syntax, transformer()).unwrap().code.unwrap(),
+            "\u{feff}import Button from \"antd/es/button\";\r\nimport \"antd/es/button/style\";\r\nlet a: number;\r\n"
+        );
+
+        let error = rewrite_source("let a: number;", Syntax::default(), transformer());
+        assert!(matches!(error, Err(TransformError::Parse { line: 1, column: 6, .. })));
+    }
+
+    #[test]
+    fn test_rewrite_source_collects_diagnostics() {
+        // config() 中 antd 的未知导出是 error 级别
+        let code = "import React from \"react\";\nimport { Button, Buton } from \"antd\";\n";
+        let rewrite =
+            rewrite_source(code, Syntax::default(), ImportTransformer::new(config()).unwrap())
+                .unwrap();
+        assert!(rewrite.has_errors());
+        assert_eq!(
+            rewrite.diagnostics,
+            vec![Diagnostic {
+                level: ReportLevel::Error,
+                location: Some(Location { line: 2, column: 18 }),
+                message: "'Buton' is not a known export of 'antd'".to_string(),
+                help: vec!["did you mean 'Button'?".to_string()],
+            }]
+        );
+
+        let rewrite = rewrite_source(
+            "import { Button } from \"antd\";",
+            Syntax::default(),
+            ImportTransformer::new(config()).unwrap(),
+        )
+        .unwrap();
+        assert!(rewrite.diagnostics.is_empty());
+        assert!(!rewrite.has_errors());
+    }
+
+    #[test]
+    fn test_rewrite_source_keeps_inner_comments() {
+        let code = "import {\n  Button, // primary action\n  /* date */ DatePicker,\n} from \"antd\";\nButton;\n";
+        let rewrite =
+            rewrite_source(code, Syntax::default(), ImportTransformer::new(config()).unwrap())
+                .unwrap();
+        assert_eq!(
+            rewrite.code.unwrap(),
+            "\
+// primary action
+/* date */
+import Button from \"antd/es/button\";
+import \"antd/es/button/style\";
+import DatePicker from \"antd/es/date-picker\";
+import \"antd/es/date-picker/style\";
+Button;
+"
+        );
+    }
+
+    #[test]
+    fn test_pass() {
+        use swc_core::ecma::transforms::testing::Tester;
+
+        let output = Tester::run(|tester| {
+            let program = tester.apply_transform(
+                import_declaration_pass(config()).unwrap(),
+                "input.js",
+                Default::default(),
+                None,
+                "import { DatePicker } from \"antd\";",
+            )?;
+            Ok(tester.print(&program, &Default::default()))
+        });
+        assert!(output.starts_with("import DatePicker from \"antd/es/date-picker\";"));
+    }
+
+    #[test]
+    fn test_unprepared_config_is_expanded() {
+        use transform_import_declaration_core::config::PresetStyle;
+
+        // 直接构造、没有经过 build() 的配置同样会展开预设和兼容配置
+        let config: PluginConfig = serde_json::from_str(
+            r#"{
+                "config": [{ "preset": "antd@4", "style": "css" }],
+                "modularizeImports": { "lodash": { "transform": "lodash/{{ member }}" } }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transform_source(
+                "import { Button } from 'antd';\nimport { debounce } from 'lodash';",
+                config.clone()
+            )
+            .unwrap(),
+            "import Button from \"antd/es/button\";\nimport \"antd/es/button/style/css\";\nimport debounce from \"lodash/debounce\";\n"
+        );
+
+        let output = swc_core::ecma::transforms::testing::Tester::run(|tester| {
+            let program = tester.apply_transform(
+                import_declaration_pass(config).unwrap(),
+                "input.js",
+                Default::default(),
+                None,
+                "import { DatePicker } from \"antd\";",
+            )?;
+            Ok(tester.print(&program, &Default::default()))
+        });
+        assert!(output.starts_with("import DatePicker from \"antd/es/date-picker\";"));
+
+        // 配置无效时 Pass 构造失败
+        let invalid = PluginConfig {
+            config: vec![TransformConfig { style: Some(PresetStyle::Css), ..Default::default() }],
+            ..Default::default()
+        };
+        let error = import_declaration_pass(invalid).err().unwrap();
+        assert!(error.to_string().contains("'style' can only be used together with 'preset'"));
+    }
+}
@@ -0,0 +1,39 @@
+//! swc wasm 插件入口
+
+use swc_core::ecma::ast::Program;
+use swc_core::ecma::visit::VisitMutWith;
+use swc_core::plugin::metadata::TransformPluginMetadataContextKind;
+use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
+use transform_import_declaration_core::cache;
+use transform_import_declaration_core::error::ConfigError;
+
+use crate::diagnostics;
+use crate::transform::ImportTransformer;
+
+#[plugin_transform]
+fn process_transform(mut program: Program, data: TransformPluginProgramMetadata) -> Program {
+    let Some(json) = data.get_transform_plugin_config() else {
+        diagnostics::report_config_error(&ConfigError::Missing);
+        return program;
+    };
+
+    // 同一个 wasm 实例中相同的配置只解析、验证和编译一次
+    let (loaded, warnings) = cache::load(&json);
+    warnings.iter().for_each(diagnostics::report_config_warning);
+    let config = match loaded {
+        Ok(config) => config,
+        Err(error) => {
+            // 配置无效时上报错误并保持代码不变
+            diagnostics::report_config_error(&error);
+            return program;
+        }
+    };
+
+    let mut transformer = ImportTransformer::from_compiled(config);
+    if let Some(filename) = data.get_context(&TransformPluginMetadataContextKind::Filename) {
+        transformer = transformer.with_filename(filename);
+    }
+    program.visit_mut_with(&mut transformer);
+
+    program
+}
@@ -1,536 +1,692 @@
-use heck::{ToKebabCase, ToLowerCamelCase, ToPascalCase, ToSnakeCase};
-use regex::Regex;
-use serde::Deserialize;
-use swc_core::common::DUMMY_SP;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use swc_core::common::{DUMMY_SP, Span};
 use swc_core::ecma::ast::{
     Ident, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
     ImportStarAsSpecifier, ModuleDecl, ModuleExportName, ModuleItem, Str,
 };
 use swc_core::ecma::visit::VisitMut;
+use transform_import_declaration_core::compile::{CompiledConfig, CompiledRule};
+pub use transform_import_declaration_core::config::{
+    FilenameCase, PluginConfig, ReportLevel, SpecifierType, TransformConfig,
+};
+use transform_import_declaration_core::error::ConfigError;
+use transform_import_declaration_core::report::{
+    LeftoverReason, LeftoverSpecifier, RewrittenSpecifier, TransformReport,
+};
+use transform_import_declaration_core::resolve::{Resolution, ResolvedImport, resolve_in};
+use transform_import_declaration_core::suggest::did_you_mean;
+use transform_import_declaration_core::version::CURRENT_DEFAULTS;
 
-/// 文件名转换规则
-#[derive(Clone, Debug, Deserialize, PartialEq, Copy)]
-#[serde(rename_all = "camelCase")]
-pub enum FilenameCase {
-    KebabCase,
-    CamelCase,
-    SnakeCase,
-    PascalCase,
-}
+use crate::diagnostics;
 
-/// 导入说明符类型
-#[derive(Clone, Debug, Deserialize, PartialEq, Copy)]
-#[serde(rename_all = "camelCase")]
-pub enum SpecifierType {
-    Default,
-    Named,
-    Namespace,
+/// 导入转换访问器
+pub struct ImportTransformer {
+    config: Arc<CompiledConfig>,
+    /// 当前处理的文件名（用于匹配 allow_full_import）
+    filename: Option<String>,
+    /// 转换报告（调用 with_report 后才收集）
+    report: Option<TransformReport>,
 }
 
-/// 单个转换配置
-#[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TransformConfig {
-    /// 源模块名称
-    pub source: String,
-    /// 输出路径模板数组
-    pub output: Vec<String>,
-    /// 文件名转换规则
-    #[serde(default)]
-    pub filename: Option<FilenameCase>,
-    /// 导入说明符类型，默认为 default
-    #[serde(default)]
-    pub specifier: Option<SpecifierType>,
-    /// 只处理指定的组件名称
-    #[serde(default)]
-    pub include: Option<Vec<String>>,
-    /// 排除指定的组件名称
-    #[serde(default)]
-    pub exclude: Option<Vec<String>>,
-}
+impl ImportTransformer {
+    /// 使用展开后的配置创建转换器
+    ///
+    /// 配置需要来自 [`PluginConfig::load`]、`PluginConfig::builder().build()` 或 [`PluginConfig::prepare`]，
+    /// 否则规则中的 extends、preset 以及兼容配置不会生效；未经 prepare 的配置在这里验证，无效时返回错误。
+    pub fn new(config: PluginConfig) -> Result<Self, ConfigError> {
+        Ok(Self::from_compiled(Arc::new(CompiledConfig::new(config)?)))
+    }
 
-/// 插件配置
-#[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PluginConfig {
-    pub config: Vec<TransformConfig>,
-}
+    /// 使用已编译的配置创建转换器（多个文件共享同一份配置）
+    pub fn from_compiled(config: Arc<CompiledConfig>) -> Self {
+        Self { config, filename: None, report: None }
+    }
 
-impl PluginConfig {
-    /// 验证配置的有效性
-    pub fn validate(&self) -> Result<(), String> {
-        for (index, config) in self.config.iter().enumerate() {
-            // 检查 output 不能为空数组
-            if config.output.is_empty() {
-                return Err(format!(
-                    "Config #{} (source: '{}'): 'output' must be a non-empty array.\n\
-                    The 'output' array defines the import paths to generate:\n\
-                    - First element: main import (with identifier)\n\
-                    - Remaining elements: side-effect imports (e.g., styles)\n\
-                    Example: [\"antd/es/{{{{ filename }}}}/index.js\", \"antd/es/{{{{ filename }}}}/style/index.css\"]",
-                    index, config.source
-                ));
-            }
+    /// 设置当前处理的文件名
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
 
-            // 检查 include 和 exclude 不能同时存在
-            if config.include.is_some() && config.exclude.is_some() {
-                return Err(format!(
-                    "Config #{} (source: '{}'): 'include' and 'exclude' cannot be used together.\n\
-                    Please choose one:\n\
-                    - Use 'include' to specify components to process (whitelist)\n\
-                    - Use 'exclude' to specify components to skip (blacklist)",
-                    index, config.source
-                ));
-            }
-        }
-        Ok(())
+    /// 收集转换报告，转换后通过 take_report 获取
+    pub fn with_report(mut self) -> Self {
+        self.report = Some(TransformReport::default());
+        self
     }
-}
 
-impl TransformConfig {
-    /// 检查组件名称是否匹配当前配置
-    ///
-    /// 匹配规则：
-    /// - 如果配置了 include（白名单），只处理列表中的组件
-    /// - 如果配置了 exclude（黑名单），处理所有组件除了列表中的
-    /// - 如果都没配置，处理所有组件
-    ///
-    /// 注意：include 和 exclude 互斥，不能同时配置
-    pub fn matches(&self, name: &str) -> bool {
-        let name_string = name.to_string();
+    /// 取出目前收集到的转换报告（没有调用 with_report 时返回 None）
+    pub fn take_report(&mut self) -> Option<TransformReport> {
+        self.report.as_mut().map(std::mem::take)
+    }
 
-        // 如果配置了 include（白名单），只处理列表中的
-        if let Some(ref include) = self.include {
-            return include.contains(&name_string);
+    /// 转换命中配置的导入声明但不修改 items，按顺序返回每条导入声明的下标及替换它的语句
+    ///
+    /// 供需要保留原文格式的工具（如 codemod）只重写这些导入声明对应的源码片段。
+    pub fn rewrite_imports(&mut self, items: &[ModuleItem]) -> Vec<(usize, Vec<ModuleItem>)> {
+        let config = Arc::clone(&self.config);
+        let mut emitted = HashSet::new();
+        let mut rewrites = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let Some(rules) = rules_for_item(&config, item) else {
+                continue;
+            };
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+                unreachable!("rules_for_item only returns rules for import declarations");
+            };
+            rewrites.push((index, self.transform_import(import_decl.clone(), rules, &mut emitted)));
         }
+        rewrites
+    }
 
-        // 如果配置了 exclude（黑名单），排除列表中的
-        if let Some(ref exclude) = self.exclude {
-            return !exclude.contains(&name_string);
+    /// 记录保留在原导入中的说明符
+    fn record_leftover(
+        &mut self,
+        source: &str,
+        imported: &str,
+        local: &Ident,
+        reason: LeftoverReason,
+        level: ReportLevel,
+    ) {
+        if let Some(report) = &mut self.report {
+            report.leftovers.push(LeftoverSpecifier {
+                source: source.to_string(),
+                imported: imported.to_string(),
+                local: local.sym.to_string(),
+                reason,
+                level,
+            });
         }
-
-        // 都没配置，匹配所有组件
-        true
     }
-}
 
-/// 文件名转换工具函数
-pub fn transform_filename(name: &str, case: &FilenameCase) -> String {
-    match case {
-        FilenameCase::KebabCase => name.to_kebab_case(),
-        FilenameCase::CamelCase => name.to_lower_camel_case(),
-        FilenameCase::SnakeCase => name.to_snake_case(),
-        FilenameCase::PascalCase => name.to_pascal_case(),
+    /// 上报不是源模块有效导出的导入名称，返回使用的诊断级别
+    fn report_unknown_export(
+        &self,
+        rule: &CompiledRule,
+        imported_name: &str,
+        suggestions: &[&str],
+        span: Span,
+    ) -> ReportLevel {
+        let config = &rule.config;
+        let level = config.on_unknown_export.unwrap_or(CURRENT_DEFAULTS.on_unknown_export);
+        diagnostics::report(
+            level,
+            span,
+            &format!("'{}' is not a known export of '{}'", imported_name, config.source),
+            did_you_mean(suggestions).as_deref(),
+        );
+        level
     }
-}
 
-/// 导入转换访问器
-pub struct ImportTransformer {
-    config: PluginConfig,
-}
+    /// 检查并上报对源模块的整包导入（默认导入和命名空间导入），返回使用的诊断级别
+    fn check_full_import(&self, rules: &[CompiledRule], import_decl: &ImportDecl) -> ReportLevel {
+        if !rules.iter().any(|rule| rule.config.prevents_full_import(self.filename.as_deref())) {
+            return ReportLevel::Keep;
+        }
 
-impl ImportTransformer {
-    pub fn new(config: PluginConfig) -> Self {
-        Self { config }
+        let source = &rules[0].config.source;
+        for specifier in &import_decl.specifiers {
+            let kind = match specifier {
+                ImportSpecifier::Default(_) => "default",
+                ImportSpecifier::Namespace(_) => "namespace",
+                ImportSpecifier::Named(_) => continue,
+            };
+            diagnostics::report(
+                ReportLevel::Error,
+                import_decl.span,
+                &format!("{kind} import of '{source}' imports the whole library"),
+                Some(&format!(
+                    "import the members you need by name, e.g. `import {{ Button }} from \"{source}\"`"
+                )),
+            );
+        }
+        ReportLevel::Error
+    }
+
+    /// 上报没有被任何规则处理的命名导入（会保留整包导入，破坏 Tree Shaking），返回使用的诊断级别
+    fn report_unmatched(
+        &self,
+        rules: &[CompiledRule],
+        imported_name: &str,
+        span: Span,
+    ) -> ReportLevel {
+        let level = rules
+            .iter()
+            .map(|rule| rule.config.on_unmatched.unwrap_or(CURRENT_DEFAULTS.on_unmatched))
+            .max()
+            .unwrap_or(ReportLevel::Keep);
+        let source = rules.first().map(|rule| rule.config.source.as_str()).unwrap_or_default();
+
+        diagnostics::report(
+            level,
+            span,
+            &format!(
+                "'{imported_name}' is not matched by any rule for '{source}' and keeps importing the whole library"
+            ),
+            Some(&format!(
+                "adjust the 'include'/'exclude' lists of the rules for '{source}', or import '{imported_name}' from its own module"
+            )),
+        );
+        level
     }
 
-    /// 为给定的组件名称和配置生成导入声明
-    /// imported_name: 原始导入名称（用于生成文件名和匹配 include/exclude）
+    /// 将解析得到的导入转换为导入声明
     /// local_ident: 本地变量标识符（包含 SyntaxContext，用于保持作用域绑定关系）
+    /// emitted: 当前模块中已生成的副作用导入路径（用于去重）
     fn generate_imports(
         &self,
-        imported_name: &str,
+        imports: Vec<ResolvedImport>,
         local_ident: &Ident,
-        config: &TransformConfig,
+        emitted: &mut HashSet<String>,
     ) -> Vec<ModuleItem> {
-        let filename = &config.filename.unwrap_or(FilenameCase::CamelCase);
-
-        let transformed_filename = transform_filename(imported_name, filename);
-        let mut imports = Vec::new();
-
-        // 使用正则表达式替换，支持空格变体如 {{filename}}, {{ filename }}, {{  filename  }} 等
-        let re = Regex::new(r"\{\{\s*filename\s*\}\}").unwrap();
-
-        let specifier = config.specifier.unwrap_or(SpecifierType::Default);
+        let mut items = Vec::with_capacity(imports.len());
 
-        for (index, output_template) in config.output.iter().enumerate() {
-            let import_path = re.replace_all(output_template, &transformed_filename).to_string();
-
-            if index == 0 {
-                // 第一个 output 生成主导入（根据 specifier 类型）
-                let import_decl = match specifier {
-                    SpecifierType::Default => {
+        for import in imports {
+            match import {
+                ResolvedImport::Main { path, specifier } => {
+                    let specifier = match specifier {
                         // import Button from "path"
-                        ImportDecl {
-                            span: DUMMY_SP,
-                            specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                        SpecifierType::Default => {
+                            ImportSpecifier::Default(ImportDefaultSpecifier {
                                 span: DUMMY_SP,
                                 local: local_ident.clone(),
-                            })],
-                            src: Box::new(Str {
-                                span: DUMMY_SP,
-                                value: import_path.into(),
-                                raw: None,
-                            }),
-                            type_only: false,
-                            with: None,
-                            phase: Default::default(),
+                            })
                         }
-                    }
-                    SpecifierType::Named => {
                         // import { Button } from "path"
-                        ImportDecl {
+                        SpecifierType::Named => ImportSpecifier::Named(ImportNamedSpecifier {
                             span: DUMMY_SP,
-                            specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
-                                span: DUMMY_SP,
-                                local: local_ident.clone(),
-                                imported: None,
-                                is_type_only: false,
-                            })],
-                            src: Box::new(Str {
-                                span: DUMMY_SP,
-                                value: import_path.into(),
-                                raw: None,
-                            }),
-                            type_only: false,
-                            with: None,
-                            phase: Default::default(),
-                        }
-                    }
-                    SpecifierType::Namespace => {
+                            local: local_ident.clone(),
+                            imported: None,
+                            is_type_only: false,
+                        }),
                         // import * as Button from "path"
-                        ImportDecl {
-                            span: DUMMY_SP,
-                            specifiers: vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                        SpecifierType::Namespace => {
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier {
                                 span: DUMMY_SP,
                                 local: local_ident.clone(),
-                            })],
-                            src: Box::new(Str {
-                                span: DUMMY_SP,
-                                value: import_path.into(),
-                                raw: None,
-                            }),
-                            type_only: false,
-                            with: None,
-                            phase: Default::default(),
+                            })
                         }
-                    }
-                };
-
-                imports.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
-            } else {
-                // 后续 output 生成副作用导入
+                    };
+                    items.push(import_decl(path, vec![specifier]));
+                }
                 // import "path"
-                let import_decl = ImportDecl {
-                    span: DUMMY_SP,
-                    specifiers: vec![],
-                    src: Box::new(Str { span: DUMMY_SP, value: import_path.into(), raw: None }),
-                    type_only: false,
-                    with: None,
-                    phase: Default::default(),
-                };
-
-                imports.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+                ResolvedImport::SideEffect { path } => {
+                    if emitted.insert(path.clone()) {
+                        items.push(import_decl(path, vec![]));
+                    }
+                }
             }
         }
 
-        imports
+        items
     }
-}
 
-impl VisitMut for ImportTransformer {
-    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+    /// 按规则转换一条导入声明，返回替换它的语句
+    fn transform_import(
+        &mut self,
+        import_decl: ImportDecl,
+        rules: &[CompiledRule],
+        emitted: &mut HashSet<String>,
+    ) -> Vec<ModuleItem> {
+        let source = rules[0].config.source.as_str();
+
+        // 副作用导入（import "antd"）没有可改写的名称，保持原样
+        if import_decl.specifiers.is_empty() {
+            return vec![ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))];
+        }
+
+        // 整条声明是类型导入（import type { ... }）时保持原样：这些名称在运行时不存在，
+        // 改写只会生成指向不存在模块的导入
+        if import_decl.type_only {
+            for specifier in &import_decl.specifiers {
+                let (imported, local) = match specifier {
+                    ImportSpecifier::Named(named) => (imported_name(named), &named.local),
+                    ImportSpecifier::Default(default) => ("default".to_string(), &default.local),
+                    ImportSpecifier::Namespace(namespace) => ("*".to_string(), &namespace.local),
+                };
+                let reason = LeftoverReason::TypeOnly;
+                self.record_leftover(source, &imported, local, reason, ReportLevel::Keep);
+            }
+            return vec![ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))];
+        }
+
         let mut new_items = Vec::new();
+        let full_import_level = self.check_full_import(rules, &import_decl);
+
+        // 处理命名导入
+        let mut unprocessed_specifiers = Vec::new();
+
+        for specifier in import_decl.specifiers {
+            match specifier {
+                ImportSpecifier::Named(named) => {
+                    // 获取原始导入名称（用于匹配 include/exclude 和生成文件名）
+                    let imported_name = imported_name(&named);
+                    // 保留原始 Ident（包含 SyntaxContext，用于保持作用域绑定关系）
+                    let local_ident = &named.local;
+
+                    // 跳过 type-only 导入
+                    if named.is_type_only {
+                        self.record_leftover(
+                            source,
+                            &imported_name,
+                            local_ident,
+                            LeftoverReason::TypeOnly,
+                            ReportLevel::Keep,
+                        );
+                        unprocessed_specifiers.push(ImportSpecifier::Named(named));
+                        continue;
+                    }
 
-        for item in items.drain(..) {
-            match item {
-                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
-                    let source = &import_decl.src.value;
-
-                    // 收集所有匹配当前source的配置
-                    let matched_configs: Vec<&TransformConfig> =
-                        if let Some(source_str) = source.as_str() {
-                            self.config
-                                .config
-                                .iter()
-                                .filter(|config| config.source.as_str() == source_str)
-                                .collect()
-                        } else {
-                            Vec::new()
-                        };
-
-                    if !matched_configs.is_empty() {
-                        // 处理命名导入
-                        let mut unprocessed_specifiers = Vec::new();
-
-                        for specifier in import_decl.specifiers {
-                            match specifier {
-                                ImportSpecifier::Named(named) => {
-                                    // 获取原始导入名称（用于匹配 include/exclude 和生成文件名）
-                                    let imported_name = match &named.imported {
-                                        Some(module_export_name) => match module_export_name {
-                                            ModuleExportName::Ident(ident) => {
-                                                ident.sym.as_ref().to_string()
-                                            }
-                                            ModuleExportName::Str(s) => {
-                                                s.value.as_str().unwrap_or_default().to_string()
-                                            }
-                                        },
-                                        None => named.local.sym.as_ref().to_string(),
-                                    };
-                                    // 保留原始 Ident（包含 SyntaxContext，用于保持作用域绑定关系）
-                                    let local_ident = &named.local;
-
-                                    // 跳过 type-only 导入
-                                    if named.is_type_only {
-                                        unprocessed_specifiers.push(ImportSpecifier::Named(named));
-                                        continue;
-                                    }
-
-                                    // 尝试每个配置，找到第一个匹配的
-                                    let mut matched = false;
-                                    for config in &matched_configs {
-                                        if config.matches(&imported_name) {
-                                            // 生成转换后的导入（传递完整的 Ident 以保持 SyntaxContext）
-                                            let generated_imports = self.generate_imports(
-                                                &imported_name,
-                                                local_ident,
-                                                config,
-                                            );
-                                            new_items.extend(generated_imports);
-                                            matched = true;
-                                            break; // 找到匹配的配置后停止
-                                        }
-                                    }
-
-                                    if !matched {
-                                        // 没有任何配置匹配这个组件，保留原始导入
-                                        unprocessed_specifiers.push(ImportSpecifier::Named(named));
-                                    }
-                                }
-                                // 保留默认导入和命名空间导入
-                                other => {
-                                    unprocessed_specifiers.push(other);
-                                }
+                    // 同一 source 的规则按顺序匹配，第一个匹配的规则生效
+                    match resolve_in(rules, &imported_name) {
+                        Resolution::Resolved { rule, imports } => {
+                            if let Some(report) = &mut self.report {
+                                report.rewritten.push(RewrittenSpecifier {
+                                    source: source.to_string(),
+                                    imported: imported_name.clone(),
+                                    local: local_ident.sym.to_string(),
+                                    rule: rule.index,
+                                    paths: imports.iter().map(|i| i.path().to_string()).collect(),
+                                });
                             }
+                            // 生成转换后的导入（传递完整的 Ident 以保持 SyntaxContext）
+                            new_items.extend(self.generate_imports(imports, local_ident, emitted));
                         }
-
-                        // 如果还有未处理的说明符，保留原导入声明
-                        if !unprocessed_specifiers.is_empty() {
-                            new_items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(
-                                ImportDecl {
-                                    span: import_decl.span,
-                                    specifiers: unprocessed_specifiers,
-                                    src: import_decl.src,
-                                    type_only: import_decl.type_only,
-                                    with: import_decl.with,
-                                    phase: import_decl.phase,
+                        // 未知的导出名称保持原样
+                        Resolution::UnknownExport { rule, suggestions } => {
+                            let level = self.report_unknown_export(
+                                rule,
+                                &imported_name,
+                                &suggestions,
+                                named.span,
+                            );
+                            self.record_leftover(
+                                source,
+                                &imported_name,
+                                local_ident,
+                                LeftoverReason::UnknownExport {
+                                    rule: rule.index,
+                                    suggestions: suggestions
+                                        .iter()
+                                        .map(|s| s.to_string())
+                                        .collect(),
                                 },
-                            )));
+                                level,
+                            );
+                            unprocessed_specifiers.push(ImportSpecifier::Named(named));
+                        }
+                        // 没有任何配置匹配这个组件，保留原始导入
+                        Resolution::Unmatched | Resolution::Unconfigured => {
+                            let level = self.report_unmatched(rules, &imported_name, named.span);
+                            self.record_leftover(
+                                source,
+                                &imported_name,
+                                local_ident,
+                                LeftoverReason::Unmatched,
+                                level,
+                            );
+                            unprocessed_specifiers.push(ImportSpecifier::Named(named));
                         }
-                    } else {
-                        // 没有匹配的配置，保留原导入
-                        new_items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
                     }
                 }
-                other => {
-                    new_items.push(other);
+                // 保留默认导入和命名空间导入
+                ImportSpecifier::Default(ImportDefaultSpecifier { ref local, .. }) => {
+                    let reason = LeftoverReason::FullImport;
+                    self.record_leftover(source, "default", local, reason, full_import_level);
+                    unprocessed_specifiers.push(specifier);
+                }
+                ImportSpecifier::Namespace(ImportStarAsSpecifier { ref local, .. }) => {
+                    let reason = LeftoverReason::FullImport;
+                    self.record_leftover(source, "*", local, reason, full_import_level);
+                    unprocessed_specifiers.push(specifier);
                 }
             }
         }
 
-        *items = new_items;
+        // 如果还有未处理的说明符，保留原导入声明
+        if !unprocessed_specifiers.is_empty() {
+            new_items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: import_decl.span,
+                specifiers: unprocessed_specifiers,
+                src: import_decl.src,
+                type_only: import_decl.type_only,
+                with: import_decl.with,
+                phase: import_decl.phase,
+            })));
+        }
+
+        new_items
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_to_kebab_case() {
-        assert_eq!("Button".to_kebab_case(), "button");
-        assert_eq!("DatePicker".to_kebab_case(), "date-picker");
-        assert_eq!("MyComponent".to_kebab_case(), "my-component");
-        assert_eq!("XMLHttpRequest".to_kebab_case(), "xml-http-request");
+/// 命名导入的原始导入名称（`import { a as b }` 中的 `a`）
+fn imported_name(named: &ImportNamedSpecifier) -> String {
+    match &named.imported {
+        Some(ModuleExportName::Ident(ident)) => ident.sym.as_ref().to_string(),
+        Some(ModuleExportName::Str(s)) => s.value.as_str().unwrap_or_default().to_string(),
+        None => named.local.sym.as_ref().to_string(),
     }
+}
 
-    #[test]
-    fn test_to_camel_case() {
-        assert_eq!("Button".to_lower_camel_case(), "button");
-        assert_eq!("DatePicker".to_lower_camel_case(), "datePicker");
-        assert_eq!("MyComponent".to_lower_camel_case(), "myComponent");
-    }
+/// 导入声明的 source 配置了规则时返回这些规则
+fn rules_for_item<'a>(config: &'a CompiledConfig, item: &ModuleItem) -> Option<&'a [CompiledRule]> {
+    let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+        return None;
+    };
+    let rules = config.rules_for(import_decl.src.value.as_str()?);
+    (!rules.is_empty()).then_some(rules)
+}
 
-    #[test]
-    fn test_to_snake_case() {
-        assert_eq!("Button".to_snake_case(), "button");
-        assert_eq!("DatePicker".to_snake_case(), "date_picker");
-        assert_eq!("MyComponent".to_snake_case(), "my_component");
-    }
+/// 生成从 path 导入的导入声明
+fn import_decl(path: String, specifiers: Vec<ImportSpecifier>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Box::new(Str { span: DUMMY_SP, value: path.into(), raw: None }),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }))
+}
 
-    #[test]
-    fn test_transform_filename() {
-        assert_eq!(transform_filename("DatePicker", &FilenameCase::KebabCase), "date-picker");
-        assert_eq!(transform_filename("DatePicker", &FilenameCase::CamelCase), "datePicker");
-        assert_eq!(transform_filename("DatePicker", &FilenameCase::SnakeCase), "date_picker");
-        assert_eq!(transform_filename("DatePicker", &FilenameCase::PascalCase), "DatePicker");
+impl VisitMut for ImportTransformer {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        // 预扫描：没有任何导入命中配置时直接返回，模块保持原样且不产生任何分配
+        if !items.iter().any(|item| rules_for_item(&self.config, item).is_some()) {
+            return;
+        }
+
+        // 规则借用自配置本身，转换时需要修改报告
+        let config = Arc::clone(&self.config);
+
+        // 一次遍历重建模块：只替换命中配置的导入声明，其余语句按原顺序移入
+        let mut emitted = HashSet::new();
+        let mut rewritten = Vec::with_capacity(items.len());
+        for item in std::mem::take(items) {
+            let Some(rules) = rules_for_item(&config, &item) else {
+                rewritten.push(item);
+                continue;
+            };
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+                unreachable!("rules_for_item only returns rules for import declarations");
+            };
+            rewritten.extend(self.transform_import(import_decl, rules, &mut emitted));
+        }
+        *items = rewritten;
     }
+}
 
-    #[test]
-    fn test_config_matches_with_include() {
-        let config = TransformConfig {
-            source: "antd".to_string(),
-            filename: Some(FilenameCase::KebabCase),
-            output: vec!["antd/es/{{ filename }}.js".to_string()],
-            specifier: Some(SpecifierType::Default),
-            include: Some(vec!["Button".to_string()]),
-            exclude: None,
-        };
+#[cfg(test)]
+mod integration_tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
 
-        assert!(config.matches("Button"));
-        assert!(!config.matches("DatePicker"));
-    }
+    use swc_core::common::errors::{DiagnosticBuilder, Emitter, HANDLER, Handler, Level};
+    use swc_core::ecma::ast::{EmptyStmt, Stmt};
+    use swc_core::ecma::parser::Syntax;
+    use swc_core::ecma::transforms::testing::{Tester, test_inline};
+    use swc_core::ecma::visit::visit_mut_pass;
+    use transform_import_declaration_core::config::PresetStyle;
 
-    #[test]
-    fn test_config_matches_with_exclude() {
-        let config = TransformConfig {
-            source: "antd".to_string(),
-            filename: Some(FilenameCase::KebabCase),
-            output: vec!["antd/es/{{ filename }}.js".to_string()],
-            specifier: Some(SpecifierType::Default),
-            include: None,
-            exclude: Some(vec!["Button".to_string()]),
-        };
+    use super::*;
 
-        assert!(!config.matches("Button"));
-        assert!(config.matches("DatePicker"));
-    }
+    /// 收集到的诊断信息：(级别, 消息, 帮助信息)
+    type CollectedDiagnostic = (Level, String, Vec<String>);
 
-    #[test]
-    fn test_config_matches_without_filters() {
-        let config = TransformConfig {
-            source: "antd".to_string(),
-            filename: Some(FilenameCase::KebabCase),
-            output: vec!["antd/es/{{ filename }}.js".to_string()],
-            specifier: Some(SpecifierType::Default),
-            include: None,
-            exclude: None,
-        };
+    /// 收集诊断信息的 Emitter
+    #[derive(Clone, Default)]
+    struct CollectingEmitter(Arc<Mutex<Vec<CollectedDiagnostic>>>);
+
+    impl Emitter for CollectingEmitter {
+        fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+            let help = db.children.iter().map(|child| child.message()).collect();
+            self.0.lock().unwrap().push((db.level, db.message(), help));
+        }
+    }
 
-        assert!(config.matches("Button"));
-        assert!(config.matches("DatePicker"));
-        assert!(config.matches("AnyComponent"));
+    /// 执行转换并返回输出代码和收集到的诊断信息
+    fn transform_with_diagnostics(
+        config: PluginConfig,
+        input: &str,
+    ) -> (String, Vec<CollectedDiagnostic>) {
+        let emitter = CollectingEmitter::default();
+        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
+
+        let output = Tester::run(|tester| {
+            let program = HANDLER.set(&handler, || {
+                tester.apply_transform(
+                    visit_mut_pass(ImportTransformer::new(config).unwrap()),
+                    "input.js",
+                    Default::default(),
+                    Some(true),
+                    input,
+                )
+            })?;
+            Ok(tester.print(&program, &Default::default()))
+        });
+
+        let diagnostics = emitter.0.lock().unwrap().clone();
+        (output, diagnostics)
     }
 
     #[test]
-    fn test_config_validation_rejects_empty_output() {
-        // 测试验证逻辑：不允许空 output 数组
-        let config = PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec![],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        };
-
-        let result = config.validate();
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("'output' must be a non-empty array"));
+    fn test_unknown_export_reports_error_with_suggestion() {
+        let (output, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    exports: Some(vec!["Button".to_string(), "DatePicker".to_string()]),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            r#"import { Button, DatPicker } from "antd";"#,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        let (level, message, help) = &diagnostics[0];
+        assert_eq!(*level, Level::Error);
+        assert_eq!(message, "'DatPicker' is not a known export of 'antd'");
+        assert_eq!(help, &vec!["did you mean 'DatePicker'?".to_string()]);
+        // 未知名称保持原样
+        assert!(output.contains(r#"import Button from "antd/es/button";"#));
+        assert!(output.contains(r#"import { DatPicker } from "antd";"#));
     }
 
     #[test]
-    fn test_config_validation_rejects_both_include_and_exclude() {
-        // 测试验证逻辑：不允许同时配置 include 和 exclude
-        let config = PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: Some(vec!["Button".to_string()]),
-                exclude: Some(vec!["Table".to_string()]),
-            }],
-        };
+    fn test_unknown_export_from_manifest_as_warning() {
+        let (output, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    manifest: Some(r#"{ "Table": {}, "Tabs": {} }"#.to_string()),
+                    on_unknown_export: Some(ReportLevel::Warn),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            r#"import { Tabel } from "antd";"#,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, Level::Warning);
+        assert_eq!(diagnostics[0].2, vec!["did you mean one of 'Table', 'Tabs'?".to_string()]);
+        assert!(output.contains(r#"import { Tabel } from "antd";"#));
+    }
 
-        let result = config.validate();
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("'include' and 'exclude' cannot be used together"));
+    #[test]
+    fn test_unmatched_reports_leftover_specifiers() {
+        let (output, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    include: Some(vec!["Button".to_string()]),
+                    on_unmatched: Some(ReportLevel::Error),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            r#"import { Button, Table, Modal } from "antd";"#,
+        );
+
+        let messages: Vec<_> =
+            diagnostics.iter().map(|(level, message, _)| (*level, message.as_str())).collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    Level::Error,
+                    "'Table' is not matched by any rule for 'antd' and keeps importing the whole library"
+                ),
+                (
+                    Level::Error,
+                    "'Modal' is not matched by any rule for 'antd' and keeps importing the whole library"
+                ),
+            ]
+        );
+        assert!(output.contains(r#"import { Table, Modal } from "antd";"#));
     }
 
     #[test]
-    fn test_config_validation_allows_only_include() {
-        // 只有 include，应该通过验证
-        let config = PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: Some(vec!["Button".to_string()]),
-                exclude: None,
-            }],
-        };
+    fn test_unmatched_uses_strictest_level_of_source_rules() {
+        let (_, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![
+                    TransformConfig {
+                        source: "antd".to_string(),
+                        output: vec!["antd/es/{{ filename }}".to_string()],
+                        include: Some(vec!["Button".to_string()]),
+                        on_unmatched: Some(ReportLevel::Warn),
+                        ..Default::default()
+                    },
+                    TransformConfig {
+                        source: "antd".to_string(),
+                        output: vec!["antd/lib/{{ filename }}".to_string()],
+                        include: Some(vec!["Modal".to_string()]),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            r#"import { Table } from "antd";"#,
+        );
 
-        assert!(config.validate().is_ok());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, Level::Warning);
     }
 
     #[test]
-    fn test_config_validation_allows_only_exclude() {
-        // 只有 exclude，应该通过验证
-        let config = PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: Some(vec!["Button".to_string()]),
-            }],
-        };
-
-        assert!(config.validate().is_ok());
+    fn test_prevent_full_import_reports_default_and_namespace() {
+        let (output, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    prevent_full_import: Some(true),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            r#"import antd from "antd"; import * as all from "antd";"#,
+        );
+
+        let messages: Vec<_> = diagnostics.iter().map(|(_, message, _)| message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "default import of 'antd' imports the whole library",
+                "namespace import of 'antd' imports the whole library",
+            ]
+        );
+        assert!(diagnostics.iter().all(|(level, ..)| *level == Level::Error));
+        assert!(output.contains(r#"import antd from "antd";"#));
     }
 
     #[test]
-    fn test_config_validation_allows_neither() {
-        // 两个都没有，应该通过验证
-        let config = PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
+    fn test_prevents_full_import_with_allow_list() {
+        let config = TransformConfig {
+            source: "antd".to_string(),
+            output: vec!["antd/es/{{ filename }}".to_string()],
+            prevent_full_import: Some(true),
+            allow_full_import: Some(vec!["src/legacy/**".to_string()]),
+            ..Default::default()
         };
 
-        assert!(config.validate().is_ok());
+        assert!(config.prevents_full_import(Some("/app/src/pages/home.tsx")));
+        assert!(!config.prevents_full_import(Some("/app/src/legacy/home.tsx")));
+        assert!(config.prevents_full_import(None));
+        assert!(!TransformConfig::default().prevents_full_import(None));
     }
-}
 
-#[cfg(test)]
-mod integration_tests {
-    use swc_core::ecma::{transforms::testing::test_inline, visit::visit_mut_pass};
-
-    use super::*;
+    #[test]
+    fn test_unknown_export_keep_is_silent() {
+        let (output, diagnostics) = transform_with_diagnostics(
+            PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    exports: Some(vec!["Button".to_string()]),
+                    on_unknown_export: Some(ReportLevel::Keep),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            r#"import { Buton } from "antd";"#,
+        );
+
+        assert!(diagnostics.is_empty());
+        assert!(output.contains(r#"import { Buton } from "antd";"#));
+    }
     // Integration tests using test_inline macro
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(
+                PluginConfig::builder()
+                    .rule(
+                        TransformConfig::builder("element-plus")
+                            .preset("element-plus")
+                            .style(PresetStyle::Css)
+                    )
+                    .build()
+                    .unwrap()
+            )
+            .unwrap()
+        ),
+        test_preset_element_plus_strips_prefix,
+        r#"import { ElButton, ElDatePicker } from "element-plus";"#,
+        r#"
+import { ElButton } from "element-plus/es/components/button/index.mjs";
+import "element-plus/es/components/button/style/css.mjs";
+import { ElDatePicker } from "element-plus/es/components/date-picker/index.mjs";
+import "element-plus/es/components/date-picker/style/css.mjs";
+"#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_basic_transform,
         r#"import { Button } from "antd";"#,
         r#"import Button from "antd/es/button.js";"#
@@ -538,19 +694,24 @@ mod integration_tests {
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec![
-                    "antd/es/{{ filename }}.js".to_string(),
-                    "antd/css/{{ filename }}.css".to_string(),
-                ],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec![
+                        "antd/es/{{ filename }}.js".to_string(),
+                        "antd/css/{{ filename }}.css".to_string(),
+                    ],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_with_style_import,
         r#"import { Button } from "antd";"#,
         r#"
@@ -561,16 +722,21 @@ import "antd/css/button.css";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: Some(vec!["Button".to_string()]),
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: Some(vec!["Button".to_string()]),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_with_exclude,
         r#"import { Button, DatePicker } from "antd";"#,
         r#"
@@ -581,32 +747,38 @@ import { Button } from "antd";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![
-                TransformConfig {
-                    source: "antd".to_string(),
-                    filename: Some(FilenameCase::KebabCase),
-                    output: vec![
-                        "antd/es/{{ filename }}.js".to_string(),
-                        "antd/css/{{ filename }}.css".to_string(),
-                    ],
-                    specifier: Some(SpecifierType::Default),
-                    include: None,
-                    exclude: Some(vec!["Button".to_string()]),
-                },
-                TransformConfig {
-                    source: "antd".to_string(),
-                    filename: Some(FilenameCase::KebabCase),
-                    output: vec![
-                        "antd/es/{{ filename }}.js".to_string(),
-                        "antd/css/{{ filename }}.png".to_string(),
-                    ],
-                    specifier: Some(SpecifierType::Default),
-                    include: Some(vec!["Button".to_string()]),
-                    exclude: None,
-                },
-            ],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![
+                    TransformConfig {
+                        source: "antd".to_string(),
+                        filename: Some(FilenameCase::KebabCase),
+                        output: vec![
+                            "antd/es/{{ filename }}.js".to_string(),
+                            "antd/css/{{ filename }}.css".to_string(),
+                        ],
+                        specifier: Some(SpecifierType::Default),
+                        include: None,
+                        exclude: Some(vec!["Button".to_string()]),
+                        ..Default::default()
+                    },
+                    TransformConfig {
+                        source: "antd".to_string(),
+                        filename: Some(FilenameCase::KebabCase),
+                        output: vec![
+                            "antd/es/{{ filename }}.js".to_string(),
+                            "antd/css/{{ filename }}.png".to_string(),
+                        ],
+                        specifier: Some(SpecifierType::Default),
+                        include: Some(vec!["Button".to_string()]),
+                        exclude: None,
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_multi_config,
         r#"import { Button, DatePicker } from "antd";"#,
         r#"
@@ -619,16 +791,21 @@ import "antd/css/date-picker.css";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "lodash".to_string(),
-                filename: Some(FilenameCase::CamelCase),
-                output: vec!["lodash/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Named),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "lodash".to_string(),
+                    filename: Some(FilenameCase::CamelCase),
+                    output: vec!["lodash/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Named),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_named_specifier,
         r#"import { debounce, throttle } from "lodash";"#,
         r#"
@@ -639,16 +816,21 @@ import { throttle } from "lodash/throttle.js";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "utils".to_string(),
-                filename: Some(FilenameCase::CamelCase),
-                output: vec!["utils/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Namespace),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "utils".to_string(),
+                    filename: Some(FilenameCase::CamelCase),
+                    output: vec!["utils/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Namespace),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_namespace_specifier,
         r#"import { DateUtils, StringUtils } from "utils";"#,
         r#"
@@ -659,16 +841,21 @@ import * as StringUtils from "utils/stringUtils.js";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::SnakeCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::SnakeCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_snake_case,
         r#"import { DatePicker } from "antd";"#,
         r#"import DatePicker from "antd/es/date_picker.js";"#
@@ -676,16 +863,21 @@ import * as StringUtils from "utils/stringUtils.js";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::PascalCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::PascalCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_pascal_case,
         r#"import { DatePicker } from "antd";"#,
         r#"import DatePicker from "antd/es/DatePicker.js";"#
@@ -693,16 +885,21 @@ import * as StringUtils from "utils/stringUtils.js";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_preserve_other_imports,
         r#"
 import React from "react";
@@ -723,16 +920,21 @@ import { useState } from "react";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_syntax_context_single_usage,
         // 测试：单个变量引用
         r#"import { Button } from "antd"; console.log(Button);"#,
@@ -741,16 +943,21 @@ import { useState } from "react";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_syntax_context_multiple_usage,
         // 测试：多个变量多次引用
         r#"import { Button, DatePicker } from "antd"; console.log(Button); render(DatePicker);"#,
@@ -759,16 +966,21 @@ import { useState } from "react";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "antd".to_string(),
-                filename: Some(FilenameCase::KebabCase),
-                output: vec!["antd/es/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Default),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Default),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_syntax_context_with_alias,
         // 测试：使用别名时的变量引用
         r#"import { Button as AntButton } from "antd"; console.log(AntButton);"#,
@@ -777,16 +989,21 @@ import { useState } from "react";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "lodash".to_string(),
-                filename: Some(FilenameCase::CamelCase),
-                output: vec!["lodash/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Named),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "lodash".to_string(),
+                    filename: Some(FilenameCase::CamelCase),
+                    output: vec!["lodash/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Named),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_syntax_context_named_specifier_usage,
         // 测试：named specifier 时的变量引用
         r#"import { debounce } from "lodash"; const fn = debounce(callback, 100);"#,
@@ -795,19 +1012,201 @@ import { useState } from "react";
 
     test_inline!(
         Default::default(),
-        |_| visit_mut_pass(ImportTransformer::new(PluginConfig {
-            config: vec![TransformConfig {
-                source: "utils".to_string(),
-                filename: Some(FilenameCase::CamelCase),
-                output: vec!["utils/{{ filename }}.js".to_string()],
-                specifier: Some(SpecifierType::Namespace),
-                include: None,
-                exclude: None,
-            }],
-        })),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "utils".to_string(),
+                    filename: Some(FilenameCase::CamelCase),
+                    output: vec!["utils/{{ filename }}.js".to_string()],
+                    specifier: Some(SpecifierType::Namespace),
+                    include: None,
+                    exclude: None,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
         test_syntax_context_namespace_specifier_usage,
         // 测试：namespace specifier 时的变量引用
         r#"import { DateUtils } from "utils"; const date = DateUtils.format(new Date());"#,
         r#"import * as DateUtils from "utils/dateUtils.js"; const date = DateUtils.format(new Date());"#
     );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec![
+                        "antd/es/{{ filename }}/index.js".to_string(),
+                        "antd/es/{{ filename }}/style/index.css".to_string(),
+                    ],
+                    dependencies: Some(HashMap::from([
+                        (
+                            "Table".to_string(),
+                            vec![
+                                "Pagination".to_string(),
+                                "Checkbox".to_string(),
+                                "Spin".to_string()
+                            ],
+                        ),
+                        ("Pagination".to_string(), vec!["Select".to_string()]),
+                    ])),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
+        test_dependencies_side_effect_imports,
+        // 测试：依赖组件的样式排在组件自身样式之前，且在模块内去重
+        r#"import { Table, Checkbox } from "antd";"#,
+        r#"
+import Table from "antd/es/table/index.js";
+import "antd/es/select/style/index.css";
+import "antd/es/pagination/style/index.css";
+import "antd/es/checkbox/style/index.css";
+import "antd/es/spin/style/index.css";
+import "antd/es/table/style/index.css";
+import Checkbox from "antd/es/checkbox/index.js";
+    "#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
+        test_only_matching_imports_are_replaced_in_place,
+        // 测试：只替换命中配置的导入，其余语句的位置不变
+        r#"import React from "react"; import { Button, Table } from "antd"; const a = 1; import { Input } from "antd"; export { a };"#,
+        r#"import React from "react"; import Button from "antd/es/button"; import Table from "antd/es/table"; const a = 1; import Input from "antd/es/input"; export { a };"#
+    );
+
+    test_inline!(
+        Default::default(),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
+        test_side_effect_import_is_kept,
+        // 测试：没有说明符的副作用导入保持原样
+        r#"import "antd"; import { Button } from "antd";"#,
+        r#"import "antd"; import Button from "antd/es/button";"#
+    );
+
+    test_inline!(
+        Syntax::Typescript(Default::default()),
+        |_| visit_mut_pass(
+            ImportTransformer::new(PluginConfig {
+                config: vec![TransformConfig {
+                    source: "antd".to_string(),
+                    filename: Some(FilenameCase::KebabCase),
+                    output: vec!["antd/es/{{ filename }}".to_string()],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+        ),
+        test_type_only_declaration_is_kept,
+        // 测试：整条声明是类型导入时保持原样
+        r#"import type { ButtonProps } from "antd"; import { Button } from "antd";"#,
+        r#"import type { ButtonProps } from "antd"; import Button from "antd/es/button";"#
+    );
+
+    #[test]
+    fn test_unmatched_module_is_left_untouched() {
+        let mut transformer = ImportTransformer::new(PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                output: vec!["antd/es/{{ filename }}".to_string()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .unwrap();
+        let mut items = vec![
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: DUMMY_SP,
+                specifiers: vec![],
+                src: Box::new(Str { span: DUMMY_SP, value: "react".into(), raw: None }),
+                type_only: false,
+                with: None,
+                phase: Default::default(),
+            })),
+            ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP })),
+        ];
+        let expected = items.clone();
+        let buffer = items.as_ptr();
+
+        transformer.visit_mut_module_items(&mut items);
+
+        // 没有导入命中配置时不会重建 items
+        assert_eq!(items, expected);
+        assert_eq!(items.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn test_rewrite_imports_returns_replacements_by_index() {
+        let mut transformer = ImportTransformer::new(PluginConfig {
+            config: vec![TransformConfig {
+                source: "antd".to_string(),
+                filename: Some(FilenameCase::KebabCase),
+                output: vec!["antd/es/{{ filename }}".to_string()],
+                specifier: Some(SpecifierType::Named),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .unwrap();
+        let named = |name: &str| {
+            ImportSpecifier::Named(ImportNamedSpecifier {
+                span: DUMMY_SP,
+                local: Ident::new_no_ctxt(name.into(), DUMMY_SP),
+                imported: None,
+                is_type_only: false,
+            })
+        };
+        let items = vec![
+            import_decl("react".to_string(), vec![named("useState")]),
+            import_decl("antd".to_string(), vec![named("Button"), named("DatePicker")]),
+        ];
+        let expected = items.clone();
+
+        let rewrites = transformer.rewrite_imports(&items);
+
+        // items 保持不变，只返回命中配置的导入声明的替换语句
+        assert_eq!(items, expected);
+        assert_eq!(
+            rewrites,
+            vec![(
+                1,
+                vec![
+                    import_decl("antd/es/button".to_string(), vec![named("Button")]),
+                    import_decl("antd/es/date-picker".to_string(), vec![named("DatePicker")]),
+                ]
+            )]
+        );
+    }
 }
@@ -0,0 +1,55 @@
+//! release 构建产物（`.wasm`）的体积检查
+//!
+//! 需要先构建 wasm，因此默认忽略，通过 `pnpm size`（构建后运行 `cargo test -- --ignored wasm_size`）执行。
+//! 构建产物不存在或比源码旧时测试失败，避免检查到过期的产物。
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const WASM: &str = "wasm32-wasip1/release/swc_plugin_transform_import_declaration.wasm";
+
+/// 体积上限：当前约 1.2 MiB，重新引入 regex 等较大的依赖会超出
+const SIZE_BUDGET: u64 = 1536 * 1024;
+
+fn wasm_path() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target"));
+    target.join(WASM)
+}
+
+/// 目录下最后修改的文件的修改时间
+fn newest_modified(path: &Path) -> Option<SystemTime> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return metadata.modified().ok();
+    }
+    std::fs::read_dir(path).ok()?.filter_map(|entry| newest_modified(&entry.ok()?.path())).max()
+}
+
+#[test]
+#[ignore = "needs the release wasm build, run `pnpm size`"]
+fn test_wasm_size_is_within_budget() {
+    let path = wasm_path();
+    let metadata = std::fs::metadata(&path)
+        .unwrap_or_else(|_| panic!("{} not found, run `pnpm build` first", path.display()));
+
+    // 插件的源码包括 core crate
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let sources = [manifest_dir.join("src"), manifest_dir.join("../core/src")];
+    let built = metadata.modified().unwrap();
+    if let Some(changed) = sources.iter().filter_map(|source| newest_modified(source)).max() {
+        assert!(
+            built >= changed,
+            "{} is older than the plugin sources, run `pnpm build` again",
+            path.display()
+        );
+    }
+
+    let size = metadata.len();
+    println!("{}: {size} bytes ({:.1} KiB)", path.display(), size as f64 / 1024.0);
+    assert!(
+        size <= SIZE_BUDGET,
+        "wasm binary is {size} bytes, over the {SIZE_BUDGET} byte budget; check for new heavy dependencies"
+    );
+}